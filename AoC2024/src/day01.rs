@@ -0,0 +1,75 @@
+use aoc_core::{
+    answer::Answer,
+    example::Example,
+    parse::{parse_token, ParseError},
+    solver::Solver,
+};
+use counter::Counter;
+use std::collections::BinaryHeap;
+
+fn get_sorted_lists(lines: &[String]) -> Result<(Vec<u32>, Vec<u32>), ParseError> {
+    // Aggregate both input columns in binary heaps to maintain sorted order
+    let mut heap1: BinaryHeap<u32> = BinaryHeap::new();
+    let mut heap2: BinaryHeap<u32> = BinaryHeap::new();
+    for (i, line) in lines.iter().enumerate() {
+        let vals: Vec<&str> = line.split_whitespace().collect();
+        if vals.len() != 2 {
+            return Err(ParseError::line(
+                i,
+                line,
+                format!("Expected 2 values, found {}", vals.len()),
+            ));
+        }
+        heap1.push(parse_token(i, line, vals[0])?);
+        heap2.push(parse_token(i, line, vals[1])?);
+    }
+
+    // Convert to sorted vectors
+    Ok((heap1.into_sorted_vec(), heap2.into_sorted_vec()))
+}
+
+pub struct Day01;
+
+impl Solver for Day01 {
+    const DAY: u32 = 1;
+    type Input = (Vec<u32>, Vec<u32>);
+
+    fn parse_input(&self, lines: &[String]) -> Result<Self::Input, ParseError> {
+        get_sorted_lists(lines)
+    }
+
+    fn solve_part_1(&self, input: &Self::Input) -> Answer {
+        // Compute the element-wise absolute difference between the two lists, then sum over the result
+        let (list1, list2) = input;
+        let sum: u32 = list1
+            .iter()
+            .zip(list2.iter())
+            .map(|(&v1, &v2)| v1.abs_diff(v2))
+            .sum();
+
+        sum.into()
+    }
+
+    fn solve_part_2(&self, input: &Self::Input) -> Answer {
+        let (list1, list2) = input;
+        let list2_ctr = list2.iter().collect::<Counter<_, u32>>();
+        let sum: u32 = list1.iter().map(|n| n * list2_ctr[n]).sum();
+
+        sum.into()
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new("day01.txt").part_1(11).part_2(31)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_examples;
+
+    #[test]
+    fn examples() {
+        assert_examples(&Day01);
+    }
+}
@@ -0,0 +1,99 @@
+use aoc_core::{
+    answer::Answer,
+    example::Example,
+    parse::{parse_token, ParseError},
+    solver::Solver,
+};
+
+fn num_safe_records(reports: &[Vec<i32>], tolerate_bad_level: bool) -> u32 {
+    let mut num_safe: u32 = 0;
+    for report in reports {
+        if is_safe(report) {
+            num_safe += 1;
+        } else if tolerate_bad_level {
+            // If we have opted to tolerate a single bad level in each report, check for safety
+            // for any possible skipped level
+            num_safe += (0..report.len()).any(|i: usize| {
+                is_safe(
+                    &report
+                        .iter()
+                        .take(i) // Take all elements up to i
+                        .chain(report.iter().skip(i + 1)) // Chain together with elements beyond i
+                        .copied()
+                        .collect::<Vec<i32>>(),
+                )
+            }) as u32;
+        }
+    }
+
+    num_safe
+}
+
+/// A report is safe according to the following rules:
+///  - The levels are either all increasing or all decreasing.
+///  - Any two adjacent levels differ by at least one and at most three.
+fn is_safe(report: &[i32]) -> bool {
+    is_increasing_safely(report) || is_decreasing_safely(report)
+}
+
+fn is_increasing_safely(report: &[i32]) -> bool {
+    report
+        .iter()
+        .zip(report.iter().skip(1))
+        .all(|(a, b)| a < b && (1..=3).contains(&(b - a)))
+}
+
+fn is_decreasing_safely(report: &[i32]) -> bool {
+    report
+        .iter()
+        .zip(report.iter().skip(1))
+        .all(|(a, b)| a > b && (1..=3).contains(&(a - b)))
+}
+
+fn get_reports(lines: &[String]) -> Result<Vec<Vec<i32>>, ParseError> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            line.split_whitespace()
+                .map(|v| parse_token(i, line, v))
+                .collect()
+        })
+        .collect()
+}
+
+pub struct Day02;
+
+impl Solver for Day02 {
+    const DAY: u32 = 2;
+    type Input = Vec<Vec<i32>>;
+
+    fn parse_input(&self, lines: &[String]) -> Result<Self::Input, ParseError> {
+        get_reports(lines)
+    }
+
+    fn solve_part_1(&self, reports: &Self::Input) -> Answer {
+        let num_safe = num_safe_records(reports, false);
+        num_safe.into()
+    }
+
+    fn solve_part_2(&self, reports: &Self::Input) -> Answer {
+        let num_safe = num_safe_records(reports, true);
+        num_safe.into()
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new("day02.txt").part_1(2).part_2(4)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_examples;
+
+    #[test]
+    fn examples() {
+        assert_examples(&Day02);
+    }
+}
@@ -1,11 +1,9 @@
-use super::utils::lines_from_file;
+use aoc_core::{answer::Answer, example::Example, parse::ParseError, solver::Solver};
 use regex::Regex;
 
-const FILEPATH: &str = "inputs/day03.txt";
-
 /// Find all expressions in the input that match the multiplication pattern,
 /// then sum the result of these multiplication programs.
-fn mul_sum(lines: &String) -> u32 {
+fn mul_sum(lines: &str) -> u32 {
     // Capture X and Y in each mul(X,Y) expression, where X and Y are 1-3 digit numbers
     let pattern = r"mul\((\d{1,3}),(\d{1,3})\)";
     let re = Regex::new(pattern).unwrap();
@@ -21,7 +19,7 @@ fn mul_sum(lines: &String) -> u32 {
 /// Find all expressions in the input that match the multiplication pattern AND
 /// are enabled according to the preceeding do() and don't() instructions, then
 /// sum the result of these multiplication programs.
-fn mul_sum_enabled(lines: &String) -> u32 {
+fn mul_sum_enabled(lines: &str) -> u32 {
     // Include named capture groups for do() and don't() commands
     let pattern = r"(mul\((?<op1>[0-9]+),(?<op2>[0-9]+)\))|(?<do>do\(\))|(?<do_not>don't\(\))";
     let re = Regex::new(pattern).unwrap();
@@ -33,9 +31,9 @@ fn mul_sum_enabled(lines: &String) -> u32 {
             if enabled {
                 sum += op1.as_str().parse::<u32>().unwrap() * op2.as_str().parse::<u32>().unwrap();
             }
-        } else if let Some(_) = cap.name("do") {
+        } else if cap.name("do").is_some() {
             enabled = true;
-        } else if let Some(_) = cap.name("do_not") {
+        } else if cap.name("do_not").is_some() {
             enabled = false;
         }
     }
@@ -43,20 +41,45 @@ fn mul_sum_enabled(lines: &String) -> u32 {
     sum
 }
 
-fn get_input() -> String {
-    lines_from_file(FILEPATH)
-        .expect(&format!("Input file {FILEPATH} should exist"))
-        .join("")
+fn get_input(lines: &[String]) -> String {
+    lines.join("")
 }
 
-pub fn solve_part_1() {
-    let lines = get_input();
-    let sum = mul_sum(&lines);
-    println!("Sum of multiplication results: {sum}")
+pub struct Day03;
+
+impl Solver for Day03 {
+    const DAY: u32 = 3;
+    type Input = String;
+
+    fn parse_input(&self, lines: &[String]) -> Result<Self::Input, ParseError> {
+        Ok(get_input(lines))
+    }
+
+    fn solve_part_1(&self, lines: &Self::Input) -> Answer {
+        let sum = mul_sum(lines);
+        sum.into()
+    }
+
+    fn solve_part_2(&self, lines: &Self::Input) -> Answer {
+        let sum = mul_sum_enabled(lines);
+        sum.into()
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![
+            Example::new("day03.txt").part_1(161),
+            Example::new("day03_part2.txt").part_2(48),
+        ]
+    }
 }
 
-pub fn solve_part_2() {
-    let lines = get_input();
-    let sum = mul_sum_enabled(&lines);
-    println!("Sum of only enabled multiplication results: {sum}")
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_examples;
+
+    #[test]
+    fn examples() {
+        assert_examples(&Day03);
+    }
 }
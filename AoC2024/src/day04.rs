@@ -0,0 +1,101 @@
+use aoc_core::{
+    answer::Answer,
+    example::Example,
+    parse::ParseError,
+    solver::Solver,
+    utils::{Direction, Grid, Point},
+};
+
+/// Part 1
+struct XmasSearch {
+    word: &'static str, // The word spelled in a straight line in any direction
+}
+
+/// Part 2
+struct XMASSearch {
+    word: &'static str, // The word spelled forwards or backwards along both diagonals of an X
+}
+
+/// Return whether the word is spelled in the given direction starting at the point, without
+/// running off the edge of the grid.
+fn spells(grid: &Grid<char>, start: Point, dir: Direction, word: &str) -> bool {
+    grid.segment(start, dir, word.len())
+        .map(|pt| grid[pt])
+        .eq(word.chars())
+}
+
+trait Search {
+    fn num_matches_from_pt(&self, pt: Point, grid: &Grid<char>) -> u32;
+}
+
+impl Search for XmasSearch {
+    /// Count the directions in which the word is spelled starting at the provided point.
+    fn num_matches_from_pt(&self, pt: Point, grid: &Grid<char>) -> u32 {
+        Direction::ALL
+            .into_iter()
+            .filter(|&dir| spells(grid, pt, dir, self.word))
+            .count() as u32
+    }
+}
+
+impl Search for XMASSearch {
+    /// Check whether the provided point is the center of an X, with the word spelled in
+    /// either direction along both of its diagonals.
+    fn num_matches_from_pt(&self, pt: Point, grid: &Grid<char>) -> u32 {
+        let reversed: String = self.word.chars().rev().collect();
+        let half = self.word.len() as isize / 2;
+
+        // Each diagonal is read from the corner opposite to the direction of travel
+        [Direction::SE, Direction::SW].into_iter().all(|dir| {
+            let corner = pt - dir.vector() * half;
+            spells(grid, corner, dir, self.word) || spells(grid, corner, dir, &reversed)
+        }) as u32
+    }
+}
+
+fn num_matches_in_grid(grid: &Grid<char>, match_fn: impl Fn(Point, &Grid<char>) -> u32) -> u32 {
+    // Sum matches over all grid points
+    grid.points().map(|pt| match_fn(pt, grid)).sum()
+}
+
+pub struct Day04;
+
+impl Solver for Day04 {
+    const DAY: u32 = 4;
+    type Input = Grid<char>;
+
+    fn parse_input(&self, lines: &[String]) -> Result<Self::Input, ParseError> {
+        Grid::parse(lines, Ok)
+    }
+
+    fn solve_part_1(&self, grid: &Self::Input) -> Answer {
+        let search = XmasSearch { word: "XMAS" };
+
+        let num_matches =
+            num_matches_in_grid(grid, |pt, grid| search.num_matches_from_pt(pt, grid));
+        num_matches.into()
+    }
+
+    fn solve_part_2(&self, grid: &Self::Input) -> Answer {
+        let search = XMASSearch { word: "MAS" };
+
+        let num_matches =
+            num_matches_in_grid(grid, |pt, grid| search.num_matches_from_pt(pt, grid));
+        num_matches.into()
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new("day04.txt").part_1(18).part_2(9)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_examples;
+
+    #[test]
+    fn examples() {
+        assert_examples(&Day04);
+    }
+}
@@ -0,0 +1,141 @@
+use aoc_core::{
+    answer::Answer,
+    example::Example,
+    parse::{parse_token, ParseError},
+    solver::Solver,
+};
+use std::collections::{HashMap, HashSet};
+
+/// Reorder the provided update according to the given ordering rules.
+fn reorder_update(update: &[u32], order_rules: &HashMap<u32, HashSet<u32>>) -> Vec<u32> {
+    let mut new_update = update.to_vec();
+    for i in 0..new_update.len() {
+        let mut j = 0;
+        while j < i {
+            if order_rules.contains_key(&new_update[i])
+                && order_rules[&new_update[i]].contains(&new_update[j])
+            {
+                // If the page at i incorrectly appears before the page at j,
+                // move the page at i directly after index j
+                let page = new_update.remove(j);
+                new_update.insert(i, page);
+            } else {
+                j += 1;
+            }
+        }
+    }
+    new_update
+}
+
+/// Return whether the provided update adheres to the given ordering rules.
+fn is_update_valid(update: &[u32], order_rules: &HashMap<u32, HashSet<u32>>) -> bool {
+    for (i, page) in update.iter().enumerate() {
+        // For each page in the update, check for an ordering rule violation in any
+        // of the preceding pages, and return false if one is found
+        if update
+            .iter()
+            .take(i)
+            .any(|p| order_rules.contains_key(page) && order_rules[page].contains(p))
+        {
+            return false;
+        }
+    }
+    true // No ordering rule violations are detected
+}
+
+/// Take the sum of all values at the middle index of each provided update.
+fn middle_page_sum(updates: &[Vec<u32>]) -> u32 {
+    updates.iter().map(|update| update[update.len() / 2]).sum()
+}
+
+fn get_ordering_rules(lines: &[String]) -> Result<HashMap<u32, HashSet<u32>>, ParseError> {
+    let rule_tuples: Vec<(u32, u32)> = lines
+        .iter()
+        .enumerate()
+        .take_while(|(_, s)| !s.is_empty())
+        .map(|(i, rule)| {
+            let (p1, p2) = rule
+                .split_once('|')
+                .ok_or_else(|| ParseError::line(i, rule, "Expected a rule of the form X|Y"))?;
+            Ok((parse_token(i, rule, p1)?, parse_token(i, rule, p2)?))
+        })
+        .collect::<Result<_, _>>()?;
+
+    // To handle rules that have the same preceding page, map each "before" page
+    // to a set of all "after" pages present in the input.
+    let mut rule_map: HashMap<u32, HashSet<u32>> = HashMap::new();
+    for (p1, p2) in rule_tuples {
+        rule_map.entry(p1).or_default().insert(p2);
+    }
+    Ok(rule_map)
+}
+
+fn get_updates(lines: &[String]) -> Result<Vec<Vec<u32>>, ParseError> {
+    let Some(separator) = lines.iter().position(|s| s.is_empty()) else {
+        return Err(ParseError::end_of_input(
+            lines,
+            "Expected a blank line between the ordering rules and the updates",
+        ));
+    };
+
+    lines
+        .iter()
+        .enumerate()
+        .skip(separator + 1)
+        .map(|(i, update)| {
+            // Create sub-vec for individual update
+            update
+                .split(',')
+                .map(|p| parse_token(i, update, p))
+                .collect()
+        })
+        .collect()
+}
+
+pub struct Day05;
+
+impl Solver for Day05 {
+    const DAY: u32 = 5;
+    type Input = (HashMap<u32, HashSet<u32>>, Vec<Vec<u32>>);
+
+    fn parse_input(&self, lines: &[String]) -> Result<Self::Input, ParseError> {
+        Ok((get_ordering_rules(lines)?, get_updates(lines)?))
+    }
+
+    fn solve_part_1(&self, input: &Self::Input) -> Answer {
+        let (ordering_rules, updates) = input;
+        let valid_updates: Vec<Vec<u32>> = updates
+            .iter()
+            .filter(|update| is_update_valid(update, ordering_rules))
+            .cloned()
+            .collect();
+        let sum = middle_page_sum(&valid_updates);
+        sum.into()
+    }
+
+    fn solve_part_2(&self, input: &Self::Input) -> Answer {
+        let (ordering_rules, updates) = input;
+        let corrected_updates: Vec<Vec<u32>> = updates
+            .iter()
+            .filter(|update| !is_update_valid(update, ordering_rules))
+            .map(|update| reorder_update(update, ordering_rules))
+            .collect();
+        let sum = middle_page_sum(&corrected_updates);
+        sum.into()
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new("day05.txt").part_1(143).part_2(123)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_examples;
+
+    #[test]
+    fn examples() {
+        assert_examples(&Day05);
+    }
+}
@@ -0,0 +1,158 @@
+use aoc_core::{
+    answer::Answer,
+    example::Example,
+    parse::ParseError,
+    solver::Solver,
+    utils::{Direction, Grid, Point},
+};
+use std::collections::{HashMap, HashSet};
+
+/// Compute the set of distinct coordinates at which a single obstruction can be added
+/// to induce a loop in the path of the guard, given the starting position and direction.
+fn obstruction_positions(
+    start_pos: Point,
+    start_step: Direction,
+    grid: &Grid<char>,
+) -> HashSet<Point> {
+    let mut obstructions = HashSet::new();
+    let mut visited = HashMap::from([(start_pos, HashSet::from([start_step]))]);
+    let mut pos = start_pos;
+    let mut step = start_step;
+
+    while let Some(next) = grid.try_step(pos, step) {
+        // Simulate obstacle in front of guard, then trace path in search of a loop
+        let new_grid = sim_obstacle_in_front(pos, step, &visited, grid);
+        let new_path = walk_path(pos, step, &new_grid);
+        if has_loop(&new_path, &new_grid) {
+            obstructions.insert(new_grid.try_step(pos, step).unwrap());
+        }
+
+        // Continue traversing the original path
+        if grid[next] == '#' {
+            step = step.turn_right(); // Take a 90-degree clockwise turn at obstacle
+        } else {
+            pos = next; // Continue traveling in the same direction otherwise
+        }
+        visited.entry(pos).or_insert_with(HashSet::new).insert(step);
+    }
+
+    obstructions
+}
+
+/// Return a copy of the provided grid, but with an obstacle inserted in front of the given position
+/// according to the step direction. If the proposed obstacle location is at the original starting point,
+/// within the path taken to reach the current location, or out of bounds, do not insert an obstacle.
+fn sim_obstacle_in_front(
+    pos: Point,
+    step: Direction,
+    visited: &HashMap<Point, HashSet<Direction>>,
+    grid: &Grid<char>,
+) -> Grid<char> {
+    let mut new_grid = grid.clone();
+    if let Some(front) = grid.try_step(pos, step) {
+        if !visited.contains_key(&front) && grid[front] != '^' {
+            new_grid[front] = '#';
+        }
+    }
+    new_grid
+}
+
+/// Return the point just beyond the bottom right corner of the grid, which is never visited
+/// and so is used as the key marking a path that loops.
+fn loop_key(grid: &Grid<char>) -> Point {
+    Point::new(grid.height() as isize, grid.width() as isize)
+}
+
+/// Return whether the provided set of visited positions forms a loop, as indicated
+/// by the presence of the designated "loop key".
+fn has_loop(path: &HashMap<Point, HashSet<Direction>>, grid: &Grid<char>) -> bool {
+    path.contains_key(&loop_key(grid))
+}
+
+/// Return the set of all grid coordinates visited by the guard, starting at the given position
+/// and direction, mapped to the guard's direction(s) of travel while visiting each coordinate.
+/// This assumes that the guard takes a right turn each time she encounters an obstacle.
+///
+/// For bookkeeping purposes, if a loop is encountered, the returned set will have a designated
+/// "loop key" mapped to an empty set.
+fn walk_path(
+    start_pos: Point,
+    start_step: Direction,
+    grid: &Grid<char>,
+) -> HashMap<Point, HashSet<Direction>> {
+    let mut visited = HashMap::from([(start_pos, HashSet::from([start_step]))]);
+    let mut pos = start_pos;
+    let mut step = start_step;
+
+    while let Some(next) = grid.try_step(pos, step) {
+        if grid[next] == '#' {
+            step = step.turn_right(); // Take a 90-degree clockwise turn at obstacle
+        } else {
+            pos = next; // Continue traveling in the same direction otherwise
+
+            if visited.contains_key(&pos) && visited[&pos].contains(&step) {
+                // Position already visited in the same orientation, loop detected...
+                // Insert special loop key and exit
+                visited.insert(loop_key(grid), HashSet::default());
+                break;
+            }
+        }
+
+        // Create or update position entry with new orientation
+        visited.entry(pos).or_insert_with(HashSet::new).insert(step);
+    }
+
+    visited
+}
+
+/// Parse the grid along with the coordinates of the starting position, at which point
+/// the guard is facing up. Error if no starting point is found.
+fn get_map(lines: &[String]) -> Result<(Grid<char>, Point), ParseError> {
+    let grid = Grid::parse(lines, Ok)?;
+    let start_pos = grid
+        .find(|&c| c == '^')
+        .ok_or_else(|| ParseError::end_of_input(lines, "Starting point '^' not found in grid"))?;
+    Ok((grid, start_pos))
+}
+
+pub struct Day06;
+
+impl Solver for Day06 {
+    const DAY: u32 = 6;
+    type Input = (Grid<char>, Point);
+
+    fn parse_input(&self, lines: &[String]) -> Result<Self::Input, ParseError> {
+        get_map(lines)
+    }
+
+    fn solve_part_1(&self, (grid, start_pos): &Self::Input) -> Answer {
+        let start_step = Direction::N; // Guard starts facing up
+        let path = walk_path(*start_pos, start_step, grid);
+
+        let num_visited = path.len();
+        num_visited.into()
+    }
+
+    fn solve_part_2(&self, (grid, start_pos): &Self::Input) -> Answer {
+        let start_step = Direction::N; // Guard starts facing up
+        let obstacles = obstruction_positions(*start_pos, start_step, grid);
+
+        let num_obstructions = obstacles.len();
+        num_obstructions.into()
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new("day06.txt").part_1(41).part_2(6)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_examples;
+
+    #[test]
+    fn examples() {
+        assert_examples(&Day06);
+    }
+}
@@ -0,0 +1,113 @@
+use aoc_core::{
+    answer::Answer,
+    example::Example,
+    parse::{parse_token, ParseError},
+    solver::Solver,
+};
+
+/// Compute the sum of all calibration test values whose operands can be combined
+/// satisfy the provided equation validation function.
+fn total_calibration_result(
+    calibration_eqs: &[(u64, Vec<u64>)],
+    validation_fn: fn(&[u64], u64, u64) -> bool,
+) -> u64 {
+    calibration_eqs
+        .iter()
+        .filter(|(tst, ops)| validation_fn(&ops[1..], *tst, ops[0]))
+        .map(|(tst, _)| tst)
+        .sum()
+}
+
+/// Return whether some combination of + and * operators on the operands, evaluated
+/// from left to right, will result in the provided test value.
+fn is_valid_eq(operands: &[u64], test_val: u64, running_result: u64) -> bool {
+    if operands.is_empty() {
+        return running_result == test_val;
+    } else if running_result > test_val {
+        return false; // Early exit if running result has exceeded test value
+    }
+
+    // Recurse on both operators for remaining operands
+    is_valid_eq(&operands[1..], test_val, running_result + operands[0])
+        || is_valid_eq(&operands[1..], test_val, running_result * operands[0])
+}
+
+/// Return whether some combination of +, *, and || operators on the operands, evaluated
+/// from left to right, will result in the provided test value.
+fn is_valid_eq_with_concat(operands: &[u64], test_val: u64, running_result: u64) -> bool {
+    if operands.is_empty() {
+        return running_result == test_val;
+    } else if running_result > test_val {
+        return false; // Early exit if running result has exceeded test value
+    }
+
+    // Recurse on all 3 operators for remaining operands
+    is_valid_eq_with_concat(&operands[1..], test_val, running_result + operands[0])
+        || is_valid_eq_with_concat(&operands[1..], test_val, running_result * operands[0])
+        || is_valid_eq_with_concat(
+            &operands[1..],
+            test_val,
+            running_result * 10u64.pow(operands[0].to_string().len() as u32) + operands[0],
+        )
+}
+
+fn get_calibration_eqs(lines: &[String]) -> Result<Vec<(u64, Vec<u64>)>, ParseError> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            let (calibration, operands) = line.split_once(':').ok_or_else(|| {
+                ParseError::line(i, line, "Expected an equation of the form X: Y Z ...")
+            })?;
+            let operands: Vec<u64> = operands
+                .split_whitespace()
+                .map(|op| parse_token(i, line, op))
+                .collect::<Result<_, _>>()?;
+            if operands.is_empty() {
+                return Err(ParseError::at(
+                    i,
+                    line,
+                    &line[line.len()..],
+                    "Expected at least one operand",
+                ));
+            }
+            Ok((parse_token(i, line, calibration)?, operands))
+        })
+        .collect()
+}
+
+pub struct Day07;
+
+impl Solver for Day07 {
+    const DAY: u32 = 7;
+    type Input = Vec<(u64, Vec<u64>)>;
+
+    fn parse_input(&self, lines: &[String]) -> Result<Self::Input, ParseError> {
+        get_calibration_eqs(lines)
+    }
+
+    fn solve_part_1(&self, equations: &Self::Input) -> Answer {
+        let sum = total_calibration_result(equations, is_valid_eq);
+        sum.into()
+    }
+
+    fn solve_part_2(&self, equations: &Self::Input) -> Answer {
+        let sum = total_calibration_result(equations, is_valid_eq_with_concat);
+        sum.into()
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new("day07.txt").part_1(3749).part_2(11387)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_examples;
+
+    #[test]
+    fn examples() {
+        assert_examples(&Day07);
+    }
+}
@@ -0,0 +1,115 @@
+use aoc_core::{
+    answer::Answer,
+    example::Example,
+    parse::ParseError,
+    solver::Solver,
+    utils::{Grid, Point},
+};
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet};
+
+/// Computes the antinode locations produced by a pair of same-frequency antenna locations
+type AntinodeFn = fn(Point, Point, &Grid<char>) -> HashSet<Point>;
+
+/// Given a map of frequencies to their antenna locations, return the distinct locations
+/// of all antinodes across all frequencies according to the provided antinode location function.
+fn antinode_locations(
+    antenna_locs: &HashMap<char, HashSet<Point>>,
+    grid: &Grid<char>,
+    antinode_fn: AntinodeFn,
+) -> HashSet<Point> {
+    antenna_locs
+        .values()
+        .flat_map(|locs| {
+            // For each pairwise combination of antenna locations of the same frequency,
+            // determine the possible antinode locations, flattened across all pairwise combos
+            locs.iter()
+                .combinations(2)
+                .flat_map(|loc| antinode_fn(*loc[0], *loc[1], grid))
+        }) // Flatten antinodes across all frequencies
+        .collect()
+}
+
+/// Return a map of each frequency to the set of locations of the associated antennas.
+fn antenna_locations(grid: &Grid<char>) -> HashMap<char, HashSet<Point>> {
+    let mut locations = HashMap::new();
+    for (coords, &c) in grid.cells() {
+        if c != '.' {
+            locations
+                .entry(c)
+                .or_insert_with(HashSet::new)
+                .insert(coords);
+        }
+    }
+    locations
+}
+
+/// Given two antenna locations of the same frequency, compute the (up to) two possible antinode
+/// locations, subject to the bounds of the provided grid.
+fn get_antinode_pts(loc1: Point, loc2: Point, grid: &Grid<char>) -> HashSet<Point> {
+    // For each of the two endpoints, trace a vector from the other point to itself, then add
+    // this vector to the current point to determine potential antinode locations, filtering
+    // out those that are out of bounds
+    [(loc1, loc1 - loc2), (loc2, loc2 - loc1)]
+        .into_iter()
+        .filter_map(|(loc, step)| grid.try_step(loc, step))
+        .collect()
+}
+
+/// Given two antenna locations of the same frequency, compute all possible antinode
+/// locations, subject to the bounds of the provided grid and taking into account the
+/// effects of resonant harmonics.
+fn get_antinode_pts_with_resonance(loc1: Point, loc2: Point, grid: &Grid<char>) -> HashSet<Point> {
+    // For each of the two endpoints, trace a vector from the other point to itself, then add
+    // this vector to the current point repeatedly to determine potential antinode locations,
+    // following the ray until it leaves the grid
+    let mut antinode_locs: HashSet<Point> = HashSet::from([loc1, loc2]);
+    let loc_dirs = [(loc1, loc1 - loc2), (loc2, loc2 - loc1)];
+
+    for (start_loc, step) in loc_dirs {
+        antinode_locs.extend(grid.ray(start_loc, step));
+    }
+
+    antinode_locs
+}
+
+pub struct Day08;
+
+impl Solver for Day08 {
+    const DAY: u32 = 8;
+    type Input = Grid<char>;
+
+    fn parse_input(&self, lines: &[String]) -> Result<Self::Input, ParseError> {
+        Grid::parse(lines, Ok)
+    }
+
+    fn solve_part_1(&self, grid: &Self::Input) -> Answer {
+        let antenna_locs = antenna_locations(grid);
+        let antinode_locs = antinode_locations(&antenna_locs, grid, get_antinode_pts);
+        let num_antinode_locs = antinode_locs.len();
+        num_antinode_locs.into()
+    }
+
+    fn solve_part_2(&self, grid: &Self::Input) -> Answer {
+        let antenna_locs = antenna_locations(grid);
+        let antinode_locs =
+            antinode_locations(&antenna_locs, grid, get_antinode_pts_with_resonance);
+        let num_antinode_locs = antinode_locs.len();
+        num_antinode_locs.into()
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new("day08.txt").part_1(14).part_2(34)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_examples;
+
+    #[test]
+    fn examples() {
+        assert_examples(&Day08);
+    }
+}
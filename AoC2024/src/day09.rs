@@ -1,12 +1,18 @@
-use super::utils::lines_from_file;
+use aoc_core::{
+    answer::Answer,
+    example::Example,
+    parse::{parse_digit, ParseError},
+    solver::Solver,
+};
 use std::cmp::max;
 
-static FILEPATH: &str = "inputs/day09.txt";
+/// Spans of disk blocks, each given as (filesystem index at which the span begins, span length)
+type BlockSpans = Vec<(usize, usize)>;
 
 /// Compute the checksum of the sequence of file ID numbers.
-fn checksum(filesystem: &Vec<i32>) -> u64 {
+fn checksum(filesystem: &[i32]) -> u64 {
     filesystem
-        .into_iter()
+        .iter()
         .enumerate()
         .map(|(idx, &file_id)| idx as u64 * max(0, file_id) as u64)
         .sum()
@@ -16,12 +22,12 @@ fn checksum(filesystem: &Vec<i32>) -> u64 {
 /// available span of free space. If a sufficiently large space does not exist, the file does not move.
 /// This produces a compacted version of the filesystem that is free of fragmentation.
 fn compact_filesystem_no_frag(
-    file_blocks: &Vec<i32>,
-    block_info: (Vec<(usize, usize)>, Vec<(usize, usize)>),
+    file_blocks: &[i32],
+    block_info: (BlockSpans, BlockSpans),
 ) -> Vec<i32> {
     let (occupied_blocks, mut free_blocks) = block_info;
 
-    let mut rearranged_files = file_blocks.clone();
+    let mut rearranged_files = file_blocks.to_vec();
     for (i, num_occupied) in occupied_blocks.into_iter().rev() {
         for (idx_free, (j, num_free)) in free_blocks.iter().copied().enumerate() {
             if j > i {
@@ -49,7 +55,7 @@ fn compact_filesystem_no_frag(
 
 /// From left to right, fill in all free disk blocks with the contents of the rightmost
 /// occupied disk blocks, producing a compacted version of the filesystem.
-fn compact_filesystem(file_blocks: &Vec<i32>) -> Vec<i32> {
+fn compact_filesystem(file_blocks: &[i32]) -> Vec<i32> {
     // Form a queue out of the occupied blocks in increasing order of their file IDs
     // to be used to populate the free spaces
     let mut occupied_blocks: Vec<i32> = file_blocks.iter().copied().filter(|&id| id >= 0).collect();
@@ -74,20 +80,20 @@ fn compact_filesystem(file_blocks: &Vec<i32>) -> Vec<i32> {
 /// Return a tuple containing two vectors, each of which themselves contain tuples which represent:
 ///   1. (filesystem index at which a span of occupied blocks begin, number of occupied blocks that follow)
 ///   2. (filesystem index at which a span of free blocks begin, number of free blocks that follow)
-fn get_block_info(disk_map: &Vec<char>) -> (Vec<(usize, usize)>, Vec<(usize, usize)>) {
+fn get_block_info(disk_map: &[u8]) -> (BlockSpans, BlockSpans) {
     let mut occupied_blocks = Vec::new();
     let mut free_blocks = Vec::new();
     let mut filesystem_idx: usize = 0;
 
     for (i, &num_blocks) in disk_map.iter().enumerate() {
-        let disk_segment = (filesystem_idx, char_to_digit(num_blocks));
+        let disk_segment = (filesystem_idx, usize::from(num_blocks));
         if i % 2 == 0 {
             occupied_blocks.push(disk_segment);
         } else {
             free_blocks.push(disk_segment);
         }
 
-        filesystem_idx += char_to_digit(num_blocks);
+        filesystem_idx += usize::from(num_blocks);
     }
 
     (occupied_blocks, free_blocks)
@@ -96,47 +102,68 @@ fn get_block_info(disk_map: &Vec<char>) -> (Vec<(usize, usize)>, Vec<(usize, usi
 /// Convert the disk map into a vector representing the allocation of disk blocks,
 /// which consists of alternating sequences of postitive integer file IDs, and -1's
 /// indicating free blocks of space.
-fn get_file_blocks(disk_map: &Vec<char>) -> Vec<i32> {
+fn get_file_blocks(disk_map: &[u8]) -> Vec<i32> {
     let mut file_blocks: Vec<i32> = Vec::new();
     for (i, &disk_val) in disk_map.iter().enumerate() {
         if i % 2 == 0 {
             // Even disk map entries store the number of blocks for file with ID i/2
-            file_blocks.extend(&vec![i as i32 / 2; char_to_digit(disk_val)]);
+            file_blocks.extend(&vec![i as i32 / 2; usize::from(disk_val)]);
         } else {
             // Odd disk map entries store the number of free blocks that follow
-            file_blocks.extend(&vec![-1; char_to_digit(disk_val)]);
+            file_blocks.extend(&vec![-1; usize::from(disk_val)]);
         }
     }
 
     file_blocks
 }
 
-fn char_to_digit(c: char) -> usize {
-    c as usize - '0' as usize
-}
+fn get_disk_map(lines: &[String]) -> Result<Vec<u8>, ParseError> {
+    let Some(line) = lines.first() else {
+        return Err(ParseError::end_of_input(lines, "Expected a disk map"));
+    };
 
-fn get_disk_map() -> Vec<char> {
-    lines_from_file(FILEPATH)
-        .expect(&format!("Input file {FILEPATH} should exist"))
-        .get(0)
-        .expect(&format!("Input file {FILEPATH} should have contents"))
-        .chars()
+    line.char_indices()
+        .map(|(offset, _)| parse_digit(0, line, offset).map(|digit| digit as u8))
         .collect()
 }
 
-pub fn solve_part_1() {
-    let disk_map = get_disk_map();
-    let file_blocks = get_file_blocks(&disk_map);
-    let filesystem = compact_filesystem(&file_blocks);
-    let checksum = checksum(&filesystem);
-    println!("Checksum of the compacted filesystem: {checksum}")
+pub struct Day09;
+
+impl Solver for Day09 {
+    const DAY: u32 = 9;
+    type Input = Vec<u8>;
+
+    fn parse_input(&self, lines: &[String]) -> Result<Self::Input, ParseError> {
+        get_disk_map(lines)
+    }
+
+    fn solve_part_1(&self, disk_map: &Self::Input) -> Answer {
+        let file_blocks = get_file_blocks(disk_map);
+        let filesystem = compact_filesystem(&file_blocks);
+        let checksum = checksum(&filesystem);
+        checksum.into()
+    }
+
+    fn solve_part_2(&self, disk_map: &Self::Input) -> Answer {
+        let file_blocks = get_file_blocks(disk_map);
+        let block_info = get_block_info(disk_map);
+        let filesystem = compact_filesystem_no_frag(&file_blocks, block_info);
+        let checksum = checksum(&filesystem);
+        checksum.into()
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new("day09.txt").part_1(1928).part_2(2858)]
+    }
 }
 
-pub fn solve_part_2() {
-    let disk_map = get_disk_map();
-    let file_blocks = get_file_blocks(&disk_map);
-    let block_info = get_block_info(&disk_map);
-    let filesystem = compact_filesystem_no_frag(&file_blocks, block_info);
-    let checksum = checksum(&filesystem);
-    println!("Checksum of the compacted filesystem with no fragmentation: {checksum}")
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_examples;
+
+    #[test]
+    fn examples() {
+        assert_examples(&Day09);
+    }
 }
@@ -0,0 +1,102 @@
+use aoc_core::{
+    answer::Answer,
+    example::Example,
+    parse::ParseError,
+    solver::Solver,
+    utils::{Grid, Point},
+};
+use std::collections::HashSet;
+
+/// Compute the sum of the scores of all trailheads in the topographic trail map
+/// according to the provided scoring function.
+fn trailhead_sum(trail_map: &Grid<u8>, scoring_fn: fn(Point, &Grid<u8>) -> u32) -> u32 {
+    trail_map
+        .cells()
+        .filter_map(|(coords, &height)| {
+            // Each trailhead starts at height 0
+            if height == 0 {
+                Some(scoring_fn(coords, trail_map))
+            } else {
+                None
+            }
+        })
+        .sum()
+}
+
+/// Return the number of 9-height positions reachable from the given coordinates.
+fn trail_score(coords: Point, trail_map: &Grid<u8>, visited: &mut HashSet<Point>) -> u32 {
+    if !visited.contains(&coords) && trail_map[coords] == 9 {
+        return 1;
+    }
+
+    let mut score_sum = 0;
+    for next_coords in trail_map.neighbours(coords) {
+        // Add to the running score if the current position has not been visited through an
+        // alternate trail and it has a height of exactly one more than the previous position
+        if !visited.contains(&next_coords) && trail_map[next_coords] == trail_map[coords] + 1 {
+            score_sum += trail_score(next_coords, trail_map, visited);
+            visited.insert(next_coords);
+        }
+    }
+    score_sum
+}
+
+/// Return the number of trails passing through the given coordinates that lead to
+/// a 9-height position.
+fn trail_rating(coords: Point, trail_map: &Grid<u8>) -> u32 {
+    if trail_map[coords] == 9 {
+        return 1;
+    }
+
+    trail_map
+        .neighbours(coords)
+        .filter(|&next_coords| trail_map[next_coords] == trail_map[coords] + 1)
+        .map(|next_coords| trail_rating(next_coords, trail_map))
+        .sum()
+}
+
+fn get_trail_map(lines: &[String]) -> Result<Grid<u8>, ParseError> {
+    Grid::parse(lines, |c| {
+        c.to_digit(10)
+            .map(|height| height as u8)
+            .ok_or_else(|| format!("Expected a digit, found '{c}'"))
+    })
+}
+
+pub struct Day10;
+
+impl Solver for Day10 {
+    const DAY: u32 = 10;
+    type Input = Grid<u8>;
+
+    fn parse_input(&self, lines: &[String]) -> Result<Self::Input, ParseError> {
+        get_trail_map(lines)
+    }
+
+    fn solve_part_1(&self, trail_map: &Self::Input) -> Answer {
+        let sum = trailhead_sum(trail_map, |coords, trail_map| {
+            trail_score(coords, trail_map, &mut HashSet::new())
+        });
+        sum.into()
+    }
+
+    fn solve_part_2(&self, trail_map: &Self::Input) -> Answer {
+        let sum = trailhead_sum(trail_map, trail_rating);
+        sum.into()
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new("day10.txt").part_1(36).part_2(81)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_examples;
+
+    #[test]
+    fn examples() {
+        assert_examples(&Day10);
+    }
+}
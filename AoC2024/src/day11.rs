@@ -1,8 +1,11 @@
-use super::utils::lines_from_file;
+use aoc_core::{
+    answer::Answer,
+    example::Example,
+    parse::{parse_token, ParseError},
+    solver::Solver,
+};
 use std::{cell::RefCell, collections::HashMap};
 
-const FILEPATH: &str = "inputs/day11.txt";
-
 thread_local! {
     // Global memoization cache
     static MEMO: RefCell<HashMap<(u64, u64), u64>> = RefCell::new(HashMap::new());
@@ -21,6 +24,10 @@ fn insert_into_memo(key: (u64, u64), value: u64) {
     });
 }
 
+fn clear_memo() {
+    MEMO.with(|map| map.borrow_mut().clear());
+}
+
 /// Recursively compute the number of stones that will ultimately result from the provided
 /// stone after the given number of blinks occur.
 ///
@@ -36,7 +43,7 @@ fn num_stones_after_blinks(stone: u64, num_blinks: u64) -> u64 {
         if stone == 0 {
             // If the stone is engraved with a 0, replace it with a 1
             num_stones_after_blinks(1, num_blinks - 1)
-        } else if digit_count(stone) % 2 == 0 {
+        } else if digit_count(stone).is_multiple_of(2) {
             // If the stone is engraved with a number that has an even number of digits,
             // replace it with two stones. The left half of the digits are engraved on the
             // new left stone, and the right half of the digits are engraved on the new
@@ -57,8 +64,8 @@ fn num_stones_after_blinks(stone: u64, num_blinks: u64) -> u64 {
 
 /// Produce the stone engravings that result from performing the specified number of blinks,
 /// given the intial vector of stone engravings.
-fn simulate_blinks(stones: &Vec<u64>, num_blinks: u64) -> Vec<u64> {
-    let mut new_stones: Vec<u64> = stones.clone();
+fn simulate_blinks(stones: &[u64], num_blinks: u64) -> Vec<u64> {
+    let mut new_stones: Vec<u64> = stones.to_vec();
 
     for _ in 0..num_blinks {
         let mut curr_stones: Vec<u64> = Vec::new();
@@ -66,7 +73,7 @@ fn simulate_blinks(stones: &Vec<u64>, num_blinks: u64) -> Vec<u64> {
             if stone == 0 {
                 // If the stone is engraved with a 0, replace it with a 1
                 curr_stones.push(1);
-            } else if digit_count(stone) % 2 == 0 {
+            } else if digit_count(stone).is_multiple_of(2) {
                 // If the stone is engraved with a number that has an even number of digits,
                 // replace it with two stones. The left half of the digits are engraved on the
                 // new left stone, and the right half of the digits are engraved on the new
@@ -91,27 +98,51 @@ fn digit_count(stone: u64) -> u32 {
     (stone as f64).log10().floor() as u32 + 1
 }
 
-fn get_stones() -> Vec<u64> {
-    lines_from_file(FILEPATH)
-        .expect(&format!("Input file {FILEPATH} should exist"))
-        .get(0)
-        .expect(&format!("Input file {FILEPATH} should have contents"))
-        .split(" ")
-        .map(|s| s.parse().expect("Cannot parse string to u64"))
-        .collect()
+fn get_stones(lines: &[String]) -> Result<Vec<u64>, ParseError> {
+    let Some(line) = lines.first() else {
+        return Err(ParseError::end_of_input(lines, "Expected stone engravings"));
+    };
+    line.split(" ").map(|s| parse_token(0, line, s)).collect()
 }
 
-pub fn solve_part_1() {
-    let stones = get_stones();
-    let stones_after_blinking = simulate_blinks(&stones, 25).len();
-    println!("Number of stones after blinking 25 times: {stones_after_blinking}");
+pub struct Day11;
+
+impl Solver for Day11 {
+    const DAY: u32 = 11;
+    type Input = Vec<u64>;
+
+    fn parse_input(&self, lines: &[String]) -> Result<Self::Input, ParseError> {
+        get_stones(lines)
+    }
+
+    fn solve_part_1(&self, stones: &Self::Input) -> Answer {
+        let stones_after_blinking = simulate_blinks(stones, 25).len();
+        stones_after_blinking.into()
+    }
+
+    fn solve_part_2(&self, stones: &Self::Input) -> Answer {
+        // Start from an empty cache so that repeated runs (e.g. when benchmarking)
+        // do the same amount of work as the first
+        clear_memo();
+        let stones_after_blinking: u64 = stones
+            .iter()
+            .map(|&stone| num_stones_after_blinks(stone, 75))
+            .sum();
+        stones_after_blinking.into()
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        vec![Example::new("day11.txt").part_1(55312)]
+    }
 }
 
-pub fn solve_part_2() {
-    let stones = get_stones();
-    let stones_after_blinking: u64 = stones
-        .into_iter()
-        .map(|stone| num_stones_after_blinks(stone, 75))
-        .sum();
-    println!("Number of stones after blinking 75 times: {stones_after_blinking}");
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_examples;
+
+    #[test]
+    fn examples() {
+        assert_examples(&Day11);
+    }
 }
@@ -0,0 +1,198 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    fmt,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+/// A global allocator that counts the allocations made by each thread, for measuring the
+/// memory use of individual solvers. It is opt-in, taking effect only in a binary that
+/// installs it with `#[global_allocator]`.
+pub struct CountingAllocator;
+
+/// Whether the counting allocator has been installed, which is first known once it has
+/// served an allocation
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Counters {
+    /// Bytes currently allocated by this thread and not yet freed
+    current: usize,
+    /// Highest value of `current` since the counters were last reset
+    peak: usize,
+    total_bytes: u64,
+    count: u64,
+}
+
+thread_local! {
+    static COUNTERS: Cell<Counters> = const {
+        Cell::new(Counters {
+            current: 0,
+            peak: 0,
+            total_bytes: 0,
+            count: 0,
+        })
+    };
+}
+
+/// Update the counters of the current thread, if they are still available.
+fn update(f: impl FnOnce(&mut Counters)) {
+    let _ = COUNTERS.try_with(|counters| {
+        let mut value = counters.get();
+        f(&mut value);
+        counters.set(value);
+    });
+}
+
+fn record_alloc(size: usize) {
+    update(|counters| {
+        counters.current += size;
+        counters.peak = counters.peak.max(counters.current);
+        counters.total_bytes += size as u64;
+        counters.count += 1;
+    });
+}
+
+fn record_dealloc(size: usize) {
+    // Memory freed by a different thread than the one that allocated it is not attributed
+    update(|counters| counters.current = counters.current.saturating_sub(size));
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        INSTALLED.store(true, Ordering::Relaxed);
+        record_alloc(layout.size());
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        INSTALLED.store(true, Ordering::Relaxed);
+        record_alloc(layout.size());
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        record_dealloc(layout.size());
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        // Count a reallocation as a fresh allocation of the new size, followed by freeing
+        // the old one, since both are briefly held at once
+        record_alloc(new_size);
+        record_dealloc(layout.size());
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+/// The heap usage of a single measured piece of work.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AllocStats {
+    /// Most bytes held at once during the work, beyond those already held before it began
+    pub peak_bytes: usize,
+    /// Sum of the sizes of all allocations made
+    pub total_bytes: u64,
+    /// Number of allocations made
+    pub count: u64,
+}
+
+impl AllocStats {
+    /// Combine the heap usage of this work with that of later work on its result. The peak
+    /// counts both peaks together, as whatever this work left allocated may be held throughout.
+    pub fn then(&self, later: &AllocStats) -> AllocStats {
+        AllocStats {
+            peak_bytes: self.peak_bytes + later.peak_bytes,
+            total_bytes: self.total_bytes + later.total_bytes,
+            count: self.count + later.count,
+        }
+    }
+}
+
+/// Return whether the counting allocator is installed as the global allocator.
+pub fn is_enabled() -> bool {
+    INSTALLED.load(Ordering::Relaxed)
+}
+
+/// Run the function, measuring the allocations it makes on the current thread. The
+/// statistics are only available if the counting allocator is installed.
+pub fn measure<R>(f: impl FnOnce() -> R) -> (R, Option<AllocStats>) {
+    let mut baseline = 0;
+    update(|counters| {
+        baseline = counters.current;
+        *counters = Counters {
+            current: baseline,
+            peak: baseline,
+            total_bytes: 0,
+            count: 0,
+        };
+    });
+
+    let result = f();
+
+    let counters = COUNTERS.with(Cell::get);
+    let stats = is_enabled().then_some(AllocStats {
+        peak_bytes: counters.peak - baseline,
+        total_bytes: counters.total_bytes,
+        count: counters.count,
+    });
+    (result, stats)
+}
+
+/// Format a number of bytes with a binary unit appropriate to its magnitude.
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+impl fmt::Display for AllocStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "peak {}, total {} in {} allocations",
+            format_bytes(self.peak_bytes as u64),
+            format_bytes(self.total_bytes),
+            self.count
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::hint::black_box;
+
+    #[global_allocator]
+    static GLOBAL: CountingAllocator = CountingAllocator;
+
+    #[test]
+    fn measures_allocations_of_current_thread() {
+        let (_, stats) = measure(|| {
+            let mut held = Vec::with_capacity(10);
+            for _ in 0..10 {
+                held.push(black_box(vec![0u8; 1000]));
+            }
+            drop(held);
+            black_box(vec![0u8; 500]);
+        });
+        let stats = stats.expect("Counting allocator should be installed in tests");
+        assert!(stats.count >= 11, "{stats}");
+        assert!(stats.total_bytes >= 10_500, "{stats}");
+        assert!((10_000..10_500).contains(&stats.peak_bytes), "{stats}");
+    }
+
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+}
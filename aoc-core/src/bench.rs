@@ -0,0 +1,223 @@
+use crate::{
+    alloc::{self, AllocStats},
+    runner::{
+        alloc_columns, format_alloc, format_duration, quiet_panics, run_with_timeout, RunError,
+    },
+    solver::Registry,
+    utils::InputSource,
+};
+use std::{
+    hint::black_box,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+/// Summary statistics over a set of timing samples.
+#[derive(Debug, Clone, Copy)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Compute statistics over the given samples, which must not be empty.
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut secs: Vec<f64> = samples.iter().map(Duration::as_secs_f64).collect();
+        secs.sort_by(f64::total_cmp);
+
+        let n = secs.len();
+        let median = if n.is_multiple_of(2) {
+            (secs[n / 2 - 1] + secs[n / 2]) / 2.0
+        } else {
+            secs[n / 2]
+        };
+        let mean = secs.iter().sum::<f64>() / n as f64;
+        // Sample standard deviation, which is zero for a single sample
+        let variance = if n > 1 {
+            secs.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / (n - 1) as f64
+        } else {
+            0.0
+        };
+
+        Stats {
+            min: Duration::from_secs_f64(secs[0]),
+            median: Duration::from_secs_f64(median),
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+/// Timing statistics for parsing the input and solving a single part, along with the heap
+/// usage of each stage if the counting allocator is installed.
+pub struct BenchTimings {
+    pub parse: Stats,
+    pub solve: Stats,
+    pub parse_alloc: Option<AllocStats>,
+    pub solve_alloc: Option<AllocStats>,
+}
+
+/// The result of benchmarking the solver for a single day and part.
+pub struct BenchResult {
+    pub day: u32,
+    pub part: u32,
+    pub outcome: Result<BenchTimings, RunError>,
+}
+
+/// Benchmark the solvers for every registered day within the given days, for each of the
+/// given parts. The input is read once up front, then parsing and solving are each timed
+/// separately over the given number of iterations, following a single untimed warm-up run.
+/// Each day and part is benchmarked on a thread of its own, and is abandoned if all of its
+/// runs together take longer than the given time limit.
+pub fn run_bench(
+    registry: &Registry,
+    days: impl Iterator<Item = u32>,
+    parts: &[u32],
+    source: &InputSource,
+    iterations: usize,
+    timeout: Option<Duration>,
+) -> Vec<BenchResult> {
+    quiet_panics(|| {
+        let mut results = Vec::new();
+        for day in days {
+            let Some(solver) = registry.shared(day) else {
+                continue;
+            };
+            let lines = Arc::new(solver.read_input(source));
+
+            for &part in parts {
+                let (solver, lines) = (Arc::clone(&solver), Arc::clone(&lines));
+                let file = source.describe(day);
+                let name = format!("day{day:02}-part{part}-bench");
+                let outcome = run_with_timeout(name, timeout, move || {
+                    let lines = lines.as_ref().as_ref().map_err(Clone::clone)?;
+
+                    // Warm up caches and allocator before taking measurements, which also
+                    // ensures the input can be parsed. Heap usage is measured on this run,
+                    // as it is the same on every run
+                    let (input, parse_alloc) = alloc::measure(|| solver.parse(lines));
+                    let input = input.map_err(|err| err.with_file(file).to_string())?;
+                    let (answer, solve_alloc) =
+                        alloc::measure(|| solver.solve(part, input.as_ref()));
+                    answer?;
+
+                    let mut parse_samples = Vec::with_capacity(iterations);
+                    let mut solve_samples = Vec::with_capacity(iterations);
+                    for _ in 0..iterations {
+                        let start = Instant::now();
+                        let input = black_box(solver.parse(lines).expect("Input should parse"));
+                        parse_samples.push(start.elapsed());
+
+                        let start = Instant::now();
+                        black_box(solver.solve(part, input.as_ref())?);
+                        solve_samples.push(start.elapsed());
+                    }
+
+                    Ok(BenchTimings {
+                        parse: Stats::from_samples(&parse_samples),
+                        solve: Stats::from_samples(&solve_samples),
+                        parse_alloc,
+                        solve_alloc,
+                    })
+                });
+                let outcome = outcome.and_then(|outcome| outcome.map_err(RunError::Failed));
+                results.push(BenchResult { day, part, outcome });
+            }
+        }
+        results
+    })
+}
+
+/// Print a table of the provided benchmark results, with separate rows for the
+/// parse and solve stages of each day and part.
+pub fn print_bench(results: &[BenchResult], iterations: usize) {
+    println!("Timings over {iterations} iterations\n");
+    // Heap usage is only shown when the counting allocator is installed
+    let show_alloc = results.iter().any(|result| {
+        result
+            .outcome
+            .as_ref()
+            .is_ok_and(|timings| timings.parse_alloc.is_some())
+    });
+    let header = format!(
+        "{:>3}  {:>4}  {:<5}  {:>10}  {:>10}  {:>10}  {:>10}",
+        "Day", "Part", "Stage", "Min", "Median", "Mean", "Std dev"
+    );
+    if show_alloc {
+        println!(
+            "{header}  {}",
+            alloc_columns(["Peak", "Allocated", "Allocs"])
+        );
+    } else {
+        println!("{header}");
+    }
+    for result in results {
+        match &result.outcome {
+            Ok(timings) => {
+                for (stage, stats, alloc) in [
+                    ("parse", timings.parse, timings.parse_alloc),
+                    ("solve", timings.solve, timings.solve_alloc),
+                ] {
+                    let row = format!(
+                        "{:>3}  {:>4}  {:<5}  {:>10}  {:>10}  {:>10}  {:>10}",
+                        result.day,
+                        result.part,
+                        stage,
+                        format_duration(stats.min),
+                        format_duration(stats.median),
+                        format_duration(stats.mean),
+                        format_duration(stats.stddev),
+                    );
+                    match alloc {
+                        Some(alloc) if show_alloc => println!("{row}  {}", format_alloc(&alloc)),
+                        _ => println!("{row}"),
+                    }
+                }
+            }
+            Err(err) => println!(
+                "{:>3}  {:>4}  {}: {err}",
+                result.day,
+                result.part,
+                err.status()
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn micros(samples: &[u64]) -> Vec<Duration> {
+        samples.iter().copied().map(Duration::from_micros).collect()
+    }
+
+    fn assert_close(actual: Duration, expected_micros: f64) {
+        let actual_micros = actual.as_secs_f64() * 1e6;
+        assert!(
+            (actual_micros - expected_micros).abs() < 1e-3,
+            "{actual_micros} != {expected_micros}"
+        );
+    }
+
+    #[test]
+    fn summarizes_samples() {
+        let odd = Stats::from_samples(&micros(&[30, 10, 20]));
+        assert_close(odd.min, 10.0);
+        assert_close(odd.median, 20.0);
+        assert_close(odd.mean, 20.0);
+        assert_close(odd.stddev, 10.0);
+
+        // The median of an even number of samples is the mean of the middle two
+        let even = Stats::from_samples(&micros(&[40, 10, 30, 20]));
+        assert_close(even.median, 25.0);
+        assert_close(even.mean, 25.0);
+        assert_close(even.stddev, (500.0f64 / 3.0).sqrt());
+
+        let single = Stats::from_samples(&micros(&[7]));
+        assert_close(single.median, 7.0);
+        assert_eq!(single.stddev, Duration::ZERO);
+    }
+}
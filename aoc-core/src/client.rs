@@ -0,0 +1,366 @@
+use crate::solver::Registry;
+use std::{
+    env, fs,
+    io::{self, IsTerminal, Write},
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+/// The site that puzzle inputs are downloaded from by default
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Identify the tool and where to find its maintainers, as requested by the site
+const USER_AGENT: &str = "github.com/martinhodde/AdventOfCode (aoc runner)";
+
+/// Environment variable holding the session cookie value
+pub const SESSION_ENV_VAR: &str = "AOC_SESSION";
+
+/// Environment variable overriding the site's base URL, e.g. to use a local stand-in
+pub const BASE_URL_ENV_VAR: &str = "AOC_BASE_URL";
+
+/// Files that may contain the session cookie value, checked in order when the
+/// environment variable is not set
+fn session_files() -> Vec<PathBuf> {
+    let mut files = vec![PathBuf::from(".aoc-session")];
+    if let Some(home) = env::var_os("HOME") {
+        files.push(Path::new(&home).join(".config/aoc/session"));
+    }
+    files
+}
+
+/// Return the session cookie value from the environment or the first session file found.
+pub fn session_token() -> Result<String, String> {
+    if let Ok(token) = env::var(SESSION_ENV_VAR) {
+        return Ok(token.trim().to_string());
+    }
+    for file in session_files() {
+        if let Ok(token) = fs::read_to_string(&file) {
+            return Ok(token.trim().to_string());
+        }
+    }
+    Err(format!(
+        "No session token found: set {SESSION_ENV_VAR} or save it to one of: {}",
+        session_files()
+            .iter()
+            .map(|file| file.display().to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    ))
+}
+
+/// Authenticated client for the puzzle site.
+pub struct AocClient {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+/// Whether an input was already present on disk or had to be downloaded.
+#[derive(Debug, PartialEq, Eq)]
+pub enum FetchOutcome {
+    Cached,
+    Downloaded,
+}
+
+impl AocClient {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        AocClient {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+        }
+    }
+
+    /// Create a client using the session token and base URL from the environment, where
+    /// an explicitly provided base URL takes precedence.
+    pub fn from_env(base_url: Option<&str>) -> Result<Self, String> {
+        let base_url = match base_url {
+            Some(base_url) => base_url.to_string(),
+            None => env::var(BASE_URL_ENV_VAR).unwrap_or_else(|_| DEFAULT_BASE_URL.to_string()),
+        };
+        Ok(Self::new(&base_url, &session_token()?))
+    }
+
+    fn url(&self, year: u32, day: u32, suffix: &str) -> String {
+        format!("{}/{year}/day/{day}{suffix}", self.base_url)
+    }
+
+    /// Download the puzzle input for the given day.
+    pub fn download_input(&self, year: u32, day: u32) -> Result<String, String> {
+        let url = self.url(year, day, "/input");
+        let response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call()
+            .map_err(|err| match err {
+                ureq::Error::Status(400, _) => {
+                    format!("Request to {url} was rejected, the session token may be invalid")
+                }
+                ureq::Error::Status(404, _) => {
+                    format!("No input found at {url}, the puzzle may not be unlocked yet")
+                }
+                err => format!("Request failed: {err}"),
+            })?;
+        response
+            .into_string()
+            .map_err(|err| format!("Cannot read response from {url}: {err}"))
+    }
+
+    /// Submit an answer for the given day and part, returning the body of the response page.
+    pub fn post_answer(
+        &self,
+        year: u32,
+        day: u32,
+        part: u32,
+        answer: &str,
+    ) -> Result<String, String> {
+        let url = self.url(year, day, "/answer");
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])
+            .map_err(|err| match err {
+                ureq::Error::Status(400, _) => {
+                    format!("Request to {url} was rejected, the session token may be invalid")
+                }
+                err => format!("Request failed: {err}"),
+            })?;
+        response
+            .into_string()
+            .map_err(|err| format!("Cannot read response from {url}: {err}"))
+    }
+
+    /// Ensure the puzzle input for the given day is present at the given path, downloading
+    /// it only if the file does not exist yet or is empty.
+    pub fn fetch_input(&self, year: u32, day: u32, path: &Path) -> Result<FetchOutcome, String> {
+        if is_cached(path) {
+            return Ok(FetchOutcome::Cached);
+        }
+
+        let input = self.download_input(year, day)?;
+        write_atomically(path, &input)
+            .map_err(|err| format!("Cannot write {}: {err}", path.display()))?;
+        Ok(FetchOutcome::Downloaded)
+    }
+}
+
+/// Return whether a non-empty input file exists at the given path.
+pub fn is_cached(path: &Path) -> bool {
+    fs::metadata(path).is_ok_and(|metadata| metadata.len() > 0)
+}
+
+/// Ensure the puzzle inputs for the given days are present in the registry's inputs
+/// directory, printing where each one is found. A client, and so a session token, is only
+/// needed when some input is missing. Returns whether every input is now present.
+pub fn fetch_inputs(
+    registry: &Registry,
+    days: impl Iterator<Item = u32>,
+    base_url: &str,
+) -> Result<bool, String> {
+    let mut missing = Vec::new();
+    for day in days {
+        let path = registry.input_path(day);
+        if is_cached(&path) {
+            println!("Day {day}: already cached at {}", path.display());
+        } else {
+            missing.push((day, path));
+        }
+    }
+    if missing.is_empty() {
+        return Ok(true);
+    }
+
+    let client = AocClient::from_env(Some(base_url))?;
+    let mut success = true;
+    for (day, path) in missing {
+        match client.fetch_input(registry.year(), day, &path) {
+            Ok(_) => println!("Day {day}: downloaded to {}", path.display()),
+            Err(err) => {
+                eprintln!("Day {day}: {err}");
+                success = false;
+            }
+        }
+    }
+    Ok(success)
+}
+
+/// Offer to download the missing input for the given day to the given path when running
+/// interactively.
+pub fn offer_fetch(year: u32, day: u32, path: &Path) {
+    if !io::stdin().is_terminal() {
+        return;
+    }
+
+    print!("Input {} is missing. Fetch it now? [y/N] ", path.display());
+    let _ = io::stdout().flush();
+    let mut reply = String::new();
+    if io::stdin().read_line(&mut reply).is_err() || !reply.trim().eq_ignore_ascii_case("y") {
+        return;
+    }
+
+    let fetched = AocClient::from_env(None).and_then(|client| client.fetch_input(year, day, path));
+    if let Err(err) = fetched {
+        eprintln!("{err}");
+    }
+}
+
+/// Write the contents to a temporary file first, so that an interrupted write never
+/// leaves a partial input that would be mistaken for a cached one.
+fn write_atomically(path: &Path, contents: &str) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let tmp_path = path.with_extension("tmp");
+    fs::write(&tmp_path, contents)?;
+    fs::rename(tmp_path, path)
+}
+
+#[cfg(test)]
+pub mod mock {
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        sync::{Arc, Mutex},
+        thread,
+    };
+
+    /// A request received by the mock server.
+    #[derive(Debug, Clone)]
+    pub struct Request {
+        pub method: String,
+        pub path: String,
+        pub headers: Vec<(String, String)>,
+        pub body: String,
+    }
+
+    impl Request {
+        pub fn header(&self, name: &str) -> Option<&str> {
+            self.headers
+                .iter()
+                .find(|(key, _)| key.eq_ignore_ascii_case(name))
+                .map(|(_, value)| value.as_str())
+        }
+    }
+
+    /// A local stand-in for the puzzle site which replays the given canned responses,
+    /// as (status, body) pairs, in order and records every request it receives.
+    pub struct MockServer {
+        pub base_url: String,
+        pub requests: Arc<Mutex<Vec<Request>>>,
+    }
+
+    impl MockServer {
+        pub fn start(responses: Vec<(u16, String)>) -> Self {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let base_url = format!("http://{}", listener.local_addr().unwrap());
+            let requests = Arc::new(Mutex::new(Vec::new()));
+
+            let recorded = Arc::clone(&requests);
+            thread::spawn(move || {
+                for (status, body) in responses {
+                    let Ok((stream, _)) = listener.accept() else {
+                        return;
+                    };
+                    let mut reader = BufReader::new(stream);
+                    recorded.lock().unwrap().push(read_request(&mut reader));
+
+                    let response = format!(
+                        "HTTP/1.1 {status} Mock\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                        body.len()
+                    );
+                    reader.get_mut().write_all(response.as_bytes()).unwrap();
+                }
+            });
+
+            MockServer { base_url, requests }
+        }
+
+        pub fn requests(&self) -> Vec<Request> {
+            self.requests.lock().unwrap().clone()
+        }
+    }
+
+    fn read_request(reader: &mut impl BufRead) -> Request {
+        let mut request_line = String::new();
+        reader.read_line(&mut request_line).unwrap();
+        let mut parts = request_line.split_whitespace();
+        let method = parts.next().unwrap_or_default().to_string();
+        let path = parts.next().unwrap_or_default().to_string();
+
+        let mut headers = Vec::new();
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            match line.trim_end().split_once(':') {
+                Some((key, value)) => headers.push((key.to_string(), value.trim().to_string())),
+                None => break,
+            }
+        }
+
+        let len = headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case("content-length"))
+            .map_or(0, |(_, value)| value.parse().unwrap());
+        let mut body = vec![0; len];
+        reader.read_exact(&mut body).unwrap();
+
+        Request {
+            method,
+            path,
+            headers,
+            body: String::from_utf8(body).unwrap(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{mock::MockServer, *};
+    use crate::test_utils::TempDir;
+
+    #[test]
+    fn downloads_and_caches_input() {
+        let server = MockServer::start(vec![(200, "1 2 3\n".to_string())]);
+        let client = AocClient::new(&server.base_url, "abc123");
+        let dir = TempDir::new("client-fetch");
+        let registry = Registry::new(2024, dir.to_path_buf());
+        let path = registry.input_path(3);
+
+        assert_eq!(
+            client.fetch_input(2024, 3, &path),
+            Ok(FetchOutcome::Downloaded)
+        );
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1 2 3\n");
+
+        // A second fetch is served from disk without contacting the server
+        assert_eq!(client.fetch_input(2024, 3, &path), Ok(FetchOutcome::Cached));
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].path, "/2024/day/3/input");
+        assert!(requests[0].body.is_empty());
+        assert_eq!(requests[0].header("cookie"), Some("session=abc123"));
+        assert_eq!(requests[0].header("user-agent"), Some(USER_AGENT));
+
+        // Cached inputs are reported without needing a session or contacting the server
+        assert_eq!(fetch_inputs(&registry, 3..=3, &server.base_url), Ok(true));
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[test]
+    fn reports_locked_puzzle() {
+        let server = MockServer::start(vec![(404, "Not Found".to_string())]);
+        let client = AocClient::new(&server.base_url, "abc123");
+        let dir = TempDir::new("client-locked");
+        let path = dir.join("day25.txt");
+
+        let err = client.fetch_input(2024, 25, &path).unwrap_err();
+        assert!(err.contains("may not be unlocked yet"), "{err}");
+        assert!(!path.exists());
+    }
+}
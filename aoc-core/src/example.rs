@@ -0,0 +1,151 @@
+use crate::{
+    answer::Answer,
+    runner::isolated,
+    solver::{DynSolver, ParsedInput},
+    utils::lines_from_file,
+};
+use std::path::{Path, PathBuf};
+
+/// A worked example from a puzzle statement, stored as a fixture file in the examples
+/// directory, along with the expected answer for each part it applies to.
+#[derive(Clone)]
+pub struct Example {
+    pub file: &'static str,
+    pub part_1: Option<Answer>,
+    pub part_2: Option<Answer>,
+}
+
+impl Example {
+    pub fn new(file: &'static str) -> Self {
+        Example {
+            file,
+            part_1: None,
+            part_2: None,
+        }
+    }
+
+    pub fn part_1(mut self, answer: impl Into<Answer>) -> Self {
+        self.part_1 = Some(answer.into());
+        self
+    }
+
+    pub fn part_2(mut self, answer: impl Into<Answer>) -> Self {
+        self.part_2 = Some(answer.into());
+        self
+    }
+
+    pub fn expected(&self, part: u32) -> Option<&Answer> {
+        match part {
+            1 => self.part_1.as_ref(),
+            2 => self.part_2.as_ref(),
+            _ => None,
+        }
+    }
+
+    pub fn path(&self, examples_dir: &Path) -> PathBuf {
+        examples_dir.join(self.file)
+    }
+
+    /// Return a result for each of the given parts that has an expected answer, with the
+    /// outcome produced for that part by the given function.
+    pub fn results(
+        &self,
+        parts: &[u32],
+        mut outcome: impl FnMut(u32) -> Result<Answer, String>,
+    ) -> Vec<ExampleResult> {
+        parts
+            .iter()
+            .filter_map(|&part| {
+                let expected = self.expected(part)?.clone();
+                Some(ExampleResult {
+                    file: self.file,
+                    part,
+                    expected,
+                    outcome: outcome(part),
+                })
+            })
+            .collect()
+    }
+}
+
+/// The result of solving a single part of an example.
+pub struct ExampleResult {
+    pub file: &'static str,
+    pub part: u32,
+    pub expected: Answer,
+    pub outcome: Result<Answer, String>,
+}
+
+impl ExampleResult {
+    pub fn passed(&self) -> bool {
+        self.outcome.as_ref() == Ok(&self.expected)
+    }
+}
+
+/// Solve each part of every example declared by the given solver that has an expected answer,
+/// reading the fixtures from the given directory.
+pub fn run_examples(solver: &dyn DynSolver, examples_dir: &Path) -> Vec<ExampleResult> {
+    solver
+        .examples()
+        .iter()
+        .flat_map(|example| run_example(solver, example, examples_dir, &[1, 2]))
+        .collect()
+}
+
+/// Read the example's fixture from the given directory and parse it, naming the fixture in
+/// any error.
+pub fn parse_example(
+    solver: &dyn DynSolver,
+    example: &Example,
+    examples_dir: &Path,
+) -> Result<ParsedInput, String> {
+    let path = example.path(examples_dir);
+    let lines = lines_from_file(&path)
+        .map_err(|err| format!("Cannot read example {}: {err}", path.display()))?;
+    solver
+        .parse(&lines)
+        .map_err(|err| err.with_file(path.display().to_string()).to_string())
+}
+
+/// Solve each of the given parts of the example that has an expected answer, reading the
+/// fixture from the given directory. A panic while parsing or solving the example is
+/// reported as the outcome of each affected part.
+fn run_example(
+    solver: &dyn DynSolver,
+    example: &Example,
+    examples_dir: &Path,
+    parts: &[u32],
+) -> Vec<ExampleResult> {
+    if parts.iter().all(|&part| example.expected(part).is_none()) {
+        return Vec::new();
+    }
+
+    let input = isolated(|| parse_example(solver, example, examples_dir));
+    example.results(parts, |part| {
+        input
+            .as_ref()
+            .map_err(Clone::clone)
+            .and_then(|input| isolated(|| solver.solve(part, input.as_ref())))
+    })
+}
+
+/// Assert that the given solver declares at least one example, and that it produces
+/// the expected answer for every one of them. For use by each day's tests.
+pub fn assert_examples(solver: &dyn DynSolver, examples_dir: &Path) {
+    let results = run_examples(solver, examples_dir);
+    assert!(
+        !results.is_empty(),
+        "Solver should declare at least one example"
+    );
+
+    for result in results {
+        assert!(
+            result.passed(),
+            "Example {} part {}: expected {}, got {:?}",
+            result.file,
+            result.part,
+            result.expected,
+            result.outcome
+        );
+    }
+}
@@ -0,0 +1,313 @@
+use crate::{
+    answer::Answer,
+    example::Example,
+    parse::ParseError,
+    runner,
+    solver::{DynSolver, ParsedInput, Registry},
+};
+use std::{
+    any::Any,
+    fs::{self, File},
+    io,
+    path::{Path, PathBuf},
+    process::{Child, Command, ExitStatus},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    thread,
+    time::Duration,
+};
+
+/// The interpreter used to run Python solutions
+pub const PYTHON: &str = "python3";
+
+/// How often a running script is checked for having exited
+const POLL_INTERVAL: Duration = Duration::from_millis(5);
+
+/// A solver implemented as a standalone script, which reads the day's input from
+/// `inputs/dayNN.txt` next to the script and prints a line of the form
+/// `Part N: <description> = <answer>` for each part.
+pub struct ExternalSolver {
+    day: u32,
+    interpreter: String,
+    script: PathBuf,
+}
+
+impl ExternalSolver {
+    pub fn new(day: u32, interpreter: &str, script: impl Into<PathBuf>) -> Self {
+        ExternalSolver {
+            day,
+            interpreter: interpreter.to_string(),
+            script: script.into(),
+        }
+    }
+
+    /// Run the script on the given input and return its standard output.
+    ///
+    /// Scripts read their input relative to their own location, so the script and any
+    /// sibling modules it imports are run from a scratch copy of their directory, in which
+    /// the input file is replaced by the given lines.
+    fn execute(&self, lines: &[String]) -> Result<String, String> {
+        let scratch = scratch_dir();
+        let result = self
+            .stage(&scratch, lines)
+            .map_err(|err| format!("Cannot prepare {}: {err}", scratch.display()))
+            .and_then(|script| self.run_script(&scratch, &script));
+        let _ = fs::remove_dir_all(&scratch);
+
+        let (status, stdout, stderr) = result?;
+        if !status.success() {
+            return Err(format!(
+                "{} exited with {status}: {}",
+                self.script.display(),
+                stderr.trim().lines().last().unwrap_or_default()
+            ));
+        }
+        Ok(stdout)
+    }
+
+    /// Run the staged script until it exits, returning its exit status along with what it
+    /// printed to standard output and standard error. The output is written to files in the
+    /// scratch directory rather than pipes, so that the script can be waited on without
+    /// blocking. If the thread running the script is abandoned after running past its time
+    /// limit, the script is killed and the scratch directory removed.
+    fn run_script(
+        &self,
+        scratch: &Path,
+        script: &Path,
+    ) -> Result<(ExitStatus, String, String), String> {
+        let (stdout_path, stderr_path) = (scratch.join("stdout.log"), scratch.join("stderr.log"));
+        let child = File::create(&stdout_path)
+            .and_then(|stdout| Ok((stdout, File::create(&stderr_path)?)))
+            .and_then(|(stdout, stderr)| {
+                Command::new(&self.interpreter)
+                    .arg(script)
+                    .current_dir(scratch)
+                    .stdout(stdout)
+                    .stderr(stderr)
+                    .spawn()
+            })
+            .map_err(|err| format!("Cannot run {}: {err}", self.interpreter))?;
+
+        let child = Arc::new(Mutex::new(KillOnDrop(child)));
+        let _cancel = {
+            let (child, scratch) = (Arc::clone(&child), scratch.to_path_buf());
+            runner::on_abandon(move || {
+                child.lock().unwrap().kill();
+                let _ = fs::remove_dir_all(scratch);
+            })
+        };
+        let status = loop {
+            let exited = child.lock().unwrap().0.try_wait();
+            match exited {
+                Ok(Some(status)) => break status,
+                Ok(None) => thread::sleep(POLL_INTERVAL),
+                Err(err) => return Err(format!("Cannot wait for {}: {err}", self.interpreter)),
+            }
+        };
+
+        let read = |path: &Path| {
+            fs::read(path)
+                .map(|output| String::from_utf8_lossy(&output).into_owned())
+                .map_err(|err| format!("Cannot read output of {}: {err}", script.display()))
+        };
+        Ok((status, read(&stdout_path)?, read(&stderr_path)?))
+    }
+
+    /// Copy the script and its sibling modules into the scratch directory along with the
+    /// input, returning the location of the copied script.
+    fn stage(&self, scratch: &Path, lines: &[String]) -> io::Result<PathBuf> {
+        let inputs = scratch.join("inputs");
+        fs::create_dir_all(&inputs)?;
+        let source_dir = self.script.parent().unwrap_or(Path::new("."));
+        for entry in fs::read_dir(source_dir)? {
+            let path = entry?.path();
+            if path.is_file() && path.extension() == self.script.extension() {
+                fs::copy(&path, scratch.join(path.file_name().unwrap()))?;
+            }
+        }
+
+        let mut input = lines.join("\n");
+        input.push('\n');
+        fs::write(inputs.join(format!("day{:02}.txt", self.day)), input)?;
+        Ok(scratch.join(self.script.file_name().unwrap()))
+    }
+}
+
+/// A child process that is killed if it is still running when dropped, so that a script is
+/// never left running once nothing is waiting for its output.
+struct KillOnDrop(Child);
+
+impl KillOnDrop {
+    fn kill(&mut self) {
+        let _ = self.0.kill();
+        let _ = self.0.wait();
+    }
+}
+
+impl Drop for KillOnDrop {
+    fn drop(&mut self) {
+        self.kill();
+    }
+}
+
+/// Return a fresh directory path for a single run of an external solver.
+fn scratch_dir() -> PathBuf {
+    static NEXT_RUN: AtomicUsize = AtomicUsize::new(0);
+    std::env::temp_dir().join(format!(
+        "aoc-external-{}-{}",
+        std::process::id(),
+        NEXT_RUN.fetch_add(1, Ordering::Relaxed)
+    ))
+}
+
+/// Find the answer to the given part in the printed output of a script, taken from the
+/// text following the last `=` or, failing that, the last `:` of the line for the part.
+fn parse_output(output: &str, part: u32) -> Option<Answer> {
+    let prefix = format!("Part {part}:");
+    let line = output.lines().find_map(|line| line.strip_prefix(&prefix))?;
+    let answer = line
+        .rsplit_once('=')
+        .or_else(|| line.rsplit_once(':'))
+        .map_or(line, |(_, answer)| answer)
+        .trim();
+    Some(match answer.parse::<i64>() {
+        Ok(n) => Answer::Int(n),
+        Err(_) => Answer::Text(answer.to_string()),
+    })
+}
+
+impl DynSolver for ExternalSolver {
+    fn day(&self) -> u32 {
+        self.day
+    }
+
+    /// Scripts parse their own input and solve both parts in a single run, so the script is
+    /// run here, keeping its output, or the reason it failed, for each part to be read from.
+    fn parse(&self, lines: &[String]) -> Result<ParsedInput, ParseError> {
+        Ok(Box::new(self.execute(lines)))
+    }
+
+    fn solve(&self, part: u32, input: &dyn Any) -> Result<Answer, String> {
+        let output = input
+            .downcast_ref::<Result<String, String>>()
+            .expect("Input should have been parsed by the same solver")
+            .as_ref()
+            .map_err(Clone::clone)?;
+        parse_output(output, part).ok_or_else(|| {
+            format!(
+                "{} printed no answer for part {part}",
+                self.script.display()
+            )
+        })
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        Vec::new()
+    }
+}
+
+/// Build a registry of the Python solutions for the given year, found as `dayNN.py`
+/// scripts in the given directory.
+pub fn python_registry(year: u32, dir: &Path) -> Registry {
+    let mut registry = Registry::new(year, dir);
+    for day in 1..=25 {
+        let script = dir.join(format!("day{day:02}.py"));
+        if script.is_file() {
+            registry.register_dyn(Box::new(ExternalSolver::new(day, PYTHON, script)));
+        }
+    }
+    registry
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        runner::{run_batch, RunError},
+        test_utils::TempDir,
+    };
+
+    #[test]
+    fn parses_printed_answers() {
+        let output = "Part 1: Calibration sum = 142\nPart 2: Code: ABC\n";
+        assert_eq!(parse_output(output, 1), Some(Answer::Int(142)));
+        assert_eq!(
+            parse_output(output, 2),
+            Some(Answer::Text("ABC".to_string()))
+        );
+        assert_eq!(parse_output("Part 1: = 3", 2), None);
+    }
+
+    #[test]
+    fn runs_script_on_given_input() {
+        let dir = TempDir::new("external");
+        let runs = dir.join("runs.log");
+        fs::write(
+            dir.join("helpers.py"),
+            "import os\n\ndef read(name):\n    \
+             return open(os.path.join(os.path.dirname(__file__), name)).read().split()\n",
+        )
+        .unwrap();
+        fs::write(
+            dir.join("day03.py"),
+            format!(
+                "import helpers\nnums = [int(n) for n in helpers.read('inputs/day03.txt')]\n\
+                 open({:?}, 'a').write('run\\n')\n\
+                 print('Part 1: Sum =', sum(nums))\nprint('Part 2: Max =', max(nums))\n",
+                runs.display().to_string()
+            ),
+        )
+        .unwrap();
+
+        let registry = python_registry(2023, &dir);
+        assert_eq!(registry.days().collect::<Vec<_>>(), vec![3]);
+        fs::create_dir_all(registry.inputs_dir()).unwrap();
+        fs::write(registry.input_path(3), "4\n9 2\n").unwrap();
+        let results = run_batch(
+            &registry,
+            3..=3,
+            &[1, 2],
+            &registry.default_input(),
+            2,
+            None,
+        );
+        let outcomes: Vec<_> = results.into_iter().map(|result| result.outcome).collect();
+        assert_eq!(outcomes, vec![Ok(Answer::Int(15)), Ok(Answer::Int(9))]);
+        // Both parts are read from a single run of the script
+        assert_eq!(fs::read_to_string(&runs).unwrap(), "run\n");
+    }
+
+    #[test]
+    fn kills_script_that_runs_past_time_limit() {
+        let dir = TempDir::new("external-timeout");
+        let finished = dir.join("finished.log");
+        fs::write(
+            dir.join("day05.py"),
+            format!(
+                "import time\ntime.sleep(0.5)\nopen({:?}, 'w').write('done')\nprint('Part 1: 1')\n",
+                finished.display().to_string()
+            ),
+        )
+        .unwrap();
+
+        let registry = python_registry(2023, &dir);
+        fs::create_dir_all(registry.inputs_dir()).unwrap();
+        fs::write(registry.input_path(5), "\n").unwrap();
+        let timeout = Duration::from_millis(200);
+        let results = run_batch(
+            &registry,
+            5..=5,
+            &[1],
+            &registry.default_input(),
+            1,
+            Some(timeout),
+        );
+        assert_eq!(results[0].outcome, Err(RunError::TimedOut(timeout)));
+
+        // The script was stopped along with its thread, so never gets to finish
+        thread::sleep(Duration::from_millis(600));
+        assert!(!finished.exists());
+    }
+}
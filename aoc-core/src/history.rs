@@ -0,0 +1,450 @@
+use crate::{
+    bench::{BenchResult, Stats},
+    runner::format_duration,
+    solver::Registry,
+    toml_file,
+};
+use std::{
+    path::{Path, PathBuf},
+    process::Command,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use toml::{Table, Value};
+
+/// Smallest relative increase in mean time reported as a slowdown, so that differences too
+/// small to matter are not flagged however consistent they are
+pub const MIN_SLOWDOWN: f64 = 0.05;
+
+/// The timings of a single stage of a single day and part within a recorded bench run.
+#[derive(Debug, Clone, Copy)]
+pub struct StageRecord {
+    pub day: u32,
+    pub part: u32,
+    pub stage: &'static str,
+    pub stats: Stats,
+}
+
+/// A recorded bench run, along with the commit it was run on, if known.
+#[derive(Debug, Clone)]
+pub struct BenchRun {
+    pub commit: Option<String>,
+    /// Seconds since the Unix epoch at which the run was recorded
+    pub timestamp: u64,
+    /// Name under which the run can be compared against later
+    pub name: Option<String>,
+    pub iterations: usize,
+    pub stages: Vec<StageRecord>,
+}
+
+impl BenchRun {
+    /// Record the successful results of a bench run made now on the commit checked out in
+    /// the given directory.
+    pub fn new(
+        results: &[BenchResult],
+        iterations: usize,
+        dir: &Path,
+        name: Option<String>,
+    ) -> Self {
+        let stages = results
+            .iter()
+            .filter_map(|result| Some((result, result.outcome.as_ref().ok()?)))
+            .flat_map(|(result, timings)| {
+                [("parse", timings.parse), ("solve", timings.solve)].map(|(stage, stats)| {
+                    StageRecord {
+                        day: result.day,
+                        part: result.part,
+                        stage,
+                        stats,
+                    }
+                })
+            })
+            .collect();
+        BenchRun {
+            commit: current_commit(dir),
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .expect("Current time should be after the epoch")
+                .as_secs(),
+            name,
+            iterations,
+            stages,
+        }
+    }
+
+    /// Describe the run by its name, commit and age.
+    pub fn describe(&self) -> String {
+        let mut description = match &self.name {
+            Some(name) => format!("run '{name}'"),
+            None => "run".to_string(),
+        };
+        if let Some(commit) = &self.commit {
+            description.push_str(&format!(" on commit {commit}"));
+        }
+        let age = SystemTime::now()
+            .duration_since(UNIX_EPOCH + Duration::from_secs(self.timestamp))
+            .unwrap_or(Duration::ZERO);
+        description.push_str(&format!(" from {}", describe_age(age)));
+        description
+    }
+}
+
+/// Return the abbreviated hash of the commit checked out in the given directory, marked as
+/// dirty if there are uncommitted changes, or None if it is not in a git repository.
+fn current_commit(dir: &Path) -> Option<String> {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+    let commit = git(&["rev-parse", "--short", "HEAD"])?;
+    match git(&["status", "--porcelain"]) {
+        Some(changes) if !changes.is_empty() => Some(format!("{commit}-dirty")),
+        _ => Some(commit),
+    }
+}
+
+fn describe_age(age: Duration) -> String {
+    let secs = age.as_secs();
+    let (amount, unit) = match secs {
+        0..60 => return "just now".to_string(),
+        60..3600 => (secs / 60, "minute"),
+        3600..86400 => (secs / 3600, "hour"),
+        _ => (secs / 86400, "day"),
+    };
+    let plural = if amount == 1 { "" } else { "s" };
+    format!("{amount} {unit}{plural} ago")
+}
+
+/// Every recorded bench run of a year's solutions, oldest first.
+pub struct BenchHistory {
+    path: PathBuf,
+    runs: Vec<BenchRun>,
+}
+
+impl BenchHistory {
+    /// Load the history from the given file. A missing file is treated as having no runs.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let table = toml_file::load(path, "bench history")?;
+
+        let runs = match table.get("run") {
+            None => Vec::new(),
+            Some(runs) => runs
+                .as_array()
+                .and_then(|runs| runs.iter().map(run_from_toml).collect())
+                .ok_or_else(|| format!("Invalid bench runs in {}", path.display()))?,
+        };
+        Ok(BenchHistory {
+            path: path.to_path_buf(),
+            runs,
+        })
+    }
+
+    /// Write the history, replacing any existing history file.
+    pub fn save(&self) -> Result<(), String> {
+        let mut table = Table::new();
+        table.insert(
+            "run".to_string(),
+            Value::Array(self.runs.iter().map(run_to_toml).collect()),
+        );
+        toml_file::save(&self.path, &table)
+    }
+
+    pub fn record(&mut self, run: BenchRun) {
+        self.runs.push(run);
+    }
+
+    /// Return the latest run with the given name, or the latest run of all if no name is
+    /// given.
+    pub fn baseline(&self, name: Option<&str>) -> Result<&BenchRun, String> {
+        let found = match name {
+            Some(name) => self
+                .runs
+                .iter()
+                .rev()
+                .find(|run| run.name.as_deref() == Some(name)),
+            None => self.runs.last(),
+        };
+        found.ok_or_else(|| match name {
+            Some(name) => format!("No bench run named '{name}' in {}", self.path.display()),
+            None => format!("No previous bench run in {}", self.path.display()),
+        })
+    }
+}
+
+fn run_from_toml(value: &Value) -> Option<BenchRun> {
+    let run = value.as_table()?;
+    let string = |key: &str| match run.get(key) {
+        Some(value) => value.as_str().map(|s| Some(s.to_string())),
+        None => Some(None),
+    };
+    let stages = run
+        .get("stages")?
+        .as_array()?
+        .iter()
+        .map(stage_from_toml)
+        .collect::<Option<_>>()?;
+    Some(BenchRun {
+        commit: string("commit")?,
+        timestamp: run.get("timestamp")?.as_integer()?.try_into().ok()?,
+        name: string("name")?,
+        iterations: run.get("iterations")?.as_integer()?.try_into().ok()?,
+        stages,
+    })
+}
+
+fn stage_from_toml(value: &Value) -> Option<StageRecord> {
+    let entry = value.as_table()?;
+    let integer = |key: &str| entry.get(key)?.as_integer();
+    let nanos = |key: &str| Some(Duration::from_nanos(integer(key)?.try_into().ok()?));
+    let stage = match entry.get("stage")?.as_str()? {
+        "parse" => "parse",
+        "solve" => "solve",
+        _ => return None,
+    };
+    Some(StageRecord {
+        day: integer("day")?.try_into().ok()?,
+        part: integer("part")?.try_into().ok()?,
+        stage,
+        stats: Stats {
+            min: nanos("min_ns")?,
+            median: nanos("median_ns")?,
+            mean: nanos("mean_ns")?,
+            stddev: nanos("stddev_ns")?,
+        },
+    })
+}
+
+fn run_to_toml(run: &BenchRun) -> Value {
+    let mut entry = Table::new();
+    if let Some(commit) = &run.commit {
+        entry.insert("commit".to_string(), Value::String(commit.clone()));
+    }
+    entry.insert(
+        "timestamp".to_string(),
+        Value::Integer(run.timestamp as i64),
+    );
+    if let Some(name) = &run.name {
+        entry.insert("name".to_string(), Value::String(name.clone()));
+    }
+    entry.insert(
+        "iterations".to_string(),
+        Value::Integer(run.iterations as i64),
+    );
+    entry.insert(
+        "stages".to_string(),
+        Value::Array(run.stages.iter().map(stage_to_toml).collect()),
+    );
+    Value::Table(entry)
+}
+
+fn stage_to_toml(stage: &StageRecord) -> Value {
+    let nanos = |duration: Duration| Value::Integer(duration.as_nanos() as i64);
+    let mut entry = Table::new();
+    entry.insert("day".to_string(), Value::Integer(stage.day.into()));
+    entry.insert("part".to_string(), Value::Integer(stage.part.into()));
+    entry.insert("stage".to_string(), Value::String(stage.stage.to_string()));
+    entry.insert("min_ns".to_string(), nanos(stage.stats.min));
+    entry.insert("median_ns".to_string(), nanos(stage.stats.median));
+    entry.insert("mean_ns".to_string(), nanos(stage.stats.mean));
+    entry.insert("stddev_ns".to_string(), nanos(stage.stats.stddev));
+    Value::Table(entry)
+}
+
+/// How the timings of a single stage changed between a baseline run and the current run.
+pub struct Comparison {
+    pub day: u32,
+    pub part: u32,
+    pub stage: &'static str,
+    pub before: Stats,
+    pub after: Stats,
+    /// Relative change in mean time, positive when slower
+    pub change: f64,
+    /// Whether the stage became slower by more than can be explained by noise
+    pub slowdown: bool,
+}
+
+/// Compare each stage of the current run against the same stage of the baseline, skipping
+/// stages that the baseline does not include.
+pub fn compare(baseline: &BenchRun, current: &BenchRun) -> Vec<Comparison> {
+    current
+        .stages
+        .iter()
+        .filter_map(|after| {
+            let before = baseline.stages.iter().find(|before| {
+                (before.day, before.part, before.stage) == (after.day, after.part, after.stage)
+            })?;
+            let change = after.stats.mean.as_secs_f64() / before.stats.mean.as_secs_f64() - 1.0;
+            let slowdown = change >= MIN_SLOWDOWN
+                && is_significant(
+                    (before.stats, baseline.iterations),
+                    (after.stats, current.iterations),
+                );
+            Some(Comparison {
+                day: after.day,
+                part: after.part,
+                stage: after.stage,
+                before: before.stats,
+                after: after.stats,
+                change: if change.is_finite() { change } else { 0.0 },
+                slowdown,
+            })
+        })
+        .collect()
+}
+
+/// Return whether the mean of the second sample is greater than that of the first at the
+/// 5% significance level, by a one-sided Welch's t-test. Samples of a single iteration
+/// carry no information about their spread, so are never considered significant.
+fn is_significant(before: (Stats, usize), after: (Stats, usize)) -> bool {
+    let ((before, n1), (after, n2)) = (before, after);
+    if n1 < 2 || n2 < 2 {
+        return false;
+    }
+    let (n1, n2) = (n1 as f64, n2 as f64);
+    let var1 = before.stddev.as_secs_f64().powi(2) / n1;
+    let var2 = after.stddev.as_secs_f64().powi(2) / n2;
+    let difference = after.mean.as_secs_f64() - before.mean.as_secs_f64();
+    if var1 + var2 == 0.0 {
+        return difference > 0.0;
+    }
+
+    let t = difference / (var1 + var2).sqrt();
+    // Welch–Satterthwaite approximation of the degrees of freedom
+    let df = (var1 + var2).powi(2) / (var1.powi(2) / (n1 - 1.0) + var2.powi(2) / (n2 - 1.0));
+    t > t_critical(df)
+}
+
+/// Return the critical value of Student's t-distribution for a one-sided test at the 5%
+/// significance level, rounding the degrees of freedom down to be conservative.
+fn t_critical(df: f64) -> f64 {
+    const TABLE: [f64; 30] = [
+        6.314, 2.920, 2.353, 2.132, 2.015, 1.943, 1.895, 1.860, 1.833, 1.812, 1.796, 1.782, 1.771,
+        1.761, 1.753, 1.746, 1.740, 1.734, 1.729, 1.725, 1.721, 1.717, 1.714, 1.711, 1.708, 1.706,
+        1.703, 1.701, 1.699, 1.697,
+    ];
+    match df.floor() as usize {
+        0 => TABLE[0],
+        df @ 1..=30 => TABLE[df - 1],
+        31..=60 => 1.684,
+        61..=120 => 1.671,
+        _ => 1.645,
+    }
+}
+
+/// Print a table comparing the timings of each stage against the baseline, marking
+/// significant slowdowns.
+pub fn print_comparison(comparisons: &[Comparison], baseline: &BenchRun) {
+    println!("\nMean times compared with {}\n", baseline.describe());
+    println!(
+        "{:>3}  {:>4}  {:<5}  {:>10}  {:>10}  {:>8}",
+        "Day", "Part", "Stage", "Before", "After", "Change"
+    );
+    for comparison in comparisons {
+        println!(
+            "{:>3}  {:>4}  {:<5}  {:>10}  {:>10}  {:>+7.1}%{}",
+            comparison.day,
+            comparison.part,
+            comparison.stage,
+            format_duration(comparison.before.mean),
+            format_duration(comparison.after.mean),
+            comparison.change * 100.0,
+            if comparison.slowdown { "  SLOWER" } else { "" }
+        );
+    }
+
+    let num_slower = comparisons.iter().filter(|c| c.slowdown).count();
+    println!("\n{num_slower} significant slowdown(s)");
+}
+
+/// Record the bench run in the year's history, first comparing it against the requested
+/// baseline if any, given by name or else the previous run. Returns whether no stage became
+/// significantly slower.
+pub fn record_bench(
+    registry: &Registry,
+    results: &[BenchResult],
+    iterations: usize,
+    name: Option<String>,
+    baseline: Option<Option<String>>,
+) -> Result<bool, String> {
+    let mut history = BenchHistory::load(&registry.bench_history_path())?;
+    let run = BenchRun::new(results, iterations, &registry.source_dir(), name);
+
+    let mut no_slowdowns = true;
+    if let Some(baseline_name) = baseline {
+        let baseline = history.baseline(baseline_name.as_deref())?;
+        let comparisons = compare(baseline, &run);
+        print_comparison(&comparisons, baseline);
+        no_slowdowns = comparisons.iter().all(|comparison| !comparison.slowdown);
+    }
+
+    history.record(run);
+    history.save()?;
+    Ok(no_slowdowns)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TempDir;
+
+    fn stats(mean_micros: u64, stddev_micros: u64) -> Stats {
+        let mean = Duration::from_micros(mean_micros);
+        Stats {
+            min: mean,
+            median: mean,
+            mean,
+            stddev: Duration::from_micros(stddev_micros),
+        }
+    }
+
+    fn run(name: Option<&str>, solve: [Stats; 3]) -> BenchRun {
+        BenchRun {
+            commit: Some("abc1234".to_string()),
+            timestamp: 1_700_000_000,
+            name: name.map(str::to_string),
+            iterations: 10,
+            stages: solve
+                .into_iter()
+                .enumerate()
+                .map(|(i, stats)| StageRecord {
+                    day: i as u32 + 1,
+                    part: 1,
+                    stage: "solve",
+                    stats,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn flags_significant_slowdowns_against_baseline() {
+        let dir = TempDir::new("history");
+        let path = dir.join("bench-history.toml");
+        let mut history = BenchHistory::load(&path).unwrap();
+        assert!(history.baseline(None).is_err());
+        history.record(run(
+            Some("base"),
+            [stats(100, 2), stats(100, 40), stats(100, 1)],
+        ));
+        history.record(run(None, [stats(50, 1), stats(50, 1), stats(50, 1)]));
+        history.save().unwrap();
+
+        let history = BenchHistory::load(&path).unwrap();
+        assert_eq!(history.baseline(None).unwrap().name, None);
+        let baseline = history.baseline(Some("base")).unwrap();
+        assert_eq!(baseline.stages[1].stats.stddev, Duration::from_micros(40));
+        assert!(history.baseline(Some("other")).is_err());
+
+        // A consistent slowdown, a noisy one and one too small to matter
+        let current = run(None, [stats(120, 2), stats(120, 40), stats(102, 1)]);
+        let slowdowns: Vec<bool> = compare(baseline, &current)
+            .iter()
+            .map(|comparison| comparison.slowdown)
+            .collect();
+        assert_eq!(slowdowns, vec![true, false, false]);
+    }
+}
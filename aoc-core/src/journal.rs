@@ -0,0 +1,213 @@
+use crate::{
+    submit::{Hint, SubmitOutcome},
+    toml_file,
+};
+use std::{
+    collections::BTreeMap,
+    fmt,
+    path::{Path, PathBuf},
+};
+use toml::{Table, Value};
+
+/// An answer that was submitted and checked by the puzzle site.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attempt {
+    pub answer: String,
+    pub correct: bool,
+    pub hint: Option<Hint>,
+}
+
+impl Attempt {
+    /// Create an attempt from the verdict on a submission, unless the site did not check it.
+    pub fn from_outcome(answer: &str, outcome: &SubmitOutcome) -> Option<Self> {
+        let (correct, hint) = match outcome {
+            SubmitOutcome::Correct => (true, None),
+            SubmitOutcome::Wrong { hint, .. } => (false, *hint),
+            _ => return None,
+        };
+        Some(Attempt {
+            answer: answer.to_string(),
+            correct,
+            hint,
+        })
+    }
+
+    /// Return whether the verdict on this attempt shows that the given answer is wrong.
+    fn rules_out(&self, answer: &str) -> bool {
+        if self.answer == answer {
+            return !self.correct;
+        }
+        let (Ok(attempted), Ok(answer)) = (self.answer.parse::<i64>(), answer.parse::<i64>())
+        else {
+            return false;
+        };
+        match self.hint {
+            Some(Hint::TooHigh) => answer >= attempted,
+            Some(Hint::TooLow) => answer <= attempted,
+            None => false,
+        }
+    }
+
+    /// Describe the verdict, as stored in the journal file.
+    fn verdict(&self) -> &'static str {
+        match (self.correct, self.hint) {
+            (true, _) => "correct",
+            (false, Some(Hint::TooHigh)) => "too high",
+            (false, Some(Hint::TooLow)) => "too low",
+            (false, None) => "wrong",
+        }
+    }
+}
+
+impl fmt::Display for Attempt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.answer, self.verdict())
+    }
+}
+
+/// Every answer submitted for a single day, keyed by part, in the order they were submitted.
+pub struct Journal {
+    path: PathBuf,
+    attempts: BTreeMap<u32, Vec<Attempt>>,
+}
+
+impl Journal {
+    /// Load the journal for the given day from the given directory. A missing journal file
+    /// is treated as having no attempts.
+    pub fn load(dir: &Path, day: u32) -> Result<Self, String> {
+        let path = dir.join(format!("day{day:02}.toml"));
+        let table = toml_file::load(&path, "journal")?;
+
+        let mut attempts = BTreeMap::new();
+        for part in [1, 2] {
+            let Some(entries) = table.get(&format!("part{part}")) else {
+                continue;
+            };
+            let entries = entries
+                .as_array()
+                .and_then(|entries| entries.iter().map(attempt_from_toml).collect())
+                .ok_or_else(|| {
+                    format!(
+                        "Invalid attempts for part {part} in {}: {entries}",
+                        path.display()
+                    )
+                })?;
+            attempts.insert(part, entries);
+        }
+        Ok(Journal { path, attempts })
+    }
+
+    /// Write the journal, replacing any existing journal file.
+    pub fn save(&self) -> Result<(), String> {
+        let table: Table = self
+            .attempts
+            .iter()
+            .map(|(part, attempts)| {
+                let entries = attempts.iter().map(attempt_to_toml).collect();
+                (format!("part{part}"), Value::Array(entries))
+            })
+            .collect();
+        toml_file::save(&self.path, &table)
+    }
+
+    pub fn attempts(&self, part: u32) -> &[Attempt] {
+        self.attempts.get(&part).map_or(&[], Vec::as_slice)
+    }
+
+    pub fn record(&mut self, part: u32, attempt: Attempt) {
+        self.attempts.entry(part).or_default().push(attempt);
+    }
+
+    /// Check that the given answer is worth submitting for the given part, failing if the
+    /// part was already solved, or if the answer was already rejected or lies outside the
+    /// bounds implied by an earlier hint.
+    pub fn check(&self, part: u32, answer: &str) -> Result<(), String> {
+        let attempts = self.attempts(part);
+        if let Some(i) = attempts.iter().position(|attempt| attempt.correct) {
+            return Err(format!(
+                "Part {part} was already solved by attempt {}: {}",
+                i + 1,
+                attempts[i]
+            ));
+        }
+        match attempts
+            .iter()
+            .position(|attempt| attempt.rules_out(answer))
+        {
+            Some(i) => Err(format!(
+                "Answer {answer} is ruled out by attempt {}: {}",
+                i + 1,
+                attempts[i]
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+fn attempt_from_toml(value: &Value) -> Option<Attempt> {
+    let entry = value.as_table()?;
+    let answer = entry.get("answer")?.as_str()?.to_string();
+    let (correct, hint) = match entry.get("verdict")?.as_str()? {
+        "correct" => (true, None),
+        "wrong" => (false, None),
+        "too high" => (false, Some(Hint::TooHigh)),
+        "too low" => (false, Some(Hint::TooLow)),
+        _ => return None,
+    };
+    Some(Attempt {
+        answer,
+        correct,
+        hint,
+    })
+}
+
+fn attempt_to_toml(attempt: &Attempt) -> Value {
+    let mut entry = Table::new();
+    entry.insert("answer".to_string(), Value::String(attempt.answer.clone()));
+    entry.insert(
+        "verdict".to_string(),
+        Value::String(attempt.verdict().to_string()),
+    );
+    Value::Table(entry)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TempDir;
+
+    fn attempt(answer: &str, hint: Option<Hint>) -> Attempt {
+        Attempt {
+            answer: answer.to_string(),
+            correct: false,
+            hint,
+        }
+    }
+
+    #[test]
+    fn rules_out_rejected_and_out_of_bounds_answers() {
+        let dir = TempDir::new("journal");
+        let mut journal = Journal::load(&dir, 5).unwrap();
+        journal.record(1, attempt("abc", None));
+        journal.record(1, attempt("100", Some(Hint::TooLow)));
+        journal.record(1, attempt("200", Some(Hint::TooHigh)));
+        journal.save().unwrap();
+
+        let journal = Journal::load(&dir, 5).unwrap();
+        assert_eq!(journal.attempts(1).len(), 3);
+        assert_eq!(
+            journal.check(1, "abc"),
+            Err("Answer abc is ruled out by attempt 1: abc (wrong)".to_string())
+        );
+        assert_eq!(
+            journal.check(1, "99"),
+            Err("Answer 99 is ruled out by attempt 2: 100 (too low)".to_string())
+        );
+        assert_eq!(
+            journal.check(1, "250"),
+            Err("Answer 250 is ruled out by attempt 3: 200 (too high)".to_string())
+        );
+        assert_eq!(journal.check(1, "150"), Ok(()));
+        assert_eq!(journal.check(2, "abc"), Ok(()));
+    }
+}
@@ -0,0 +1,53 @@
+//! Shared tooling for Advent of Code solutions: the solver interface and registry, input
+//! management and parsing helpers, along with the runner, benchmarks and their history, answer verification
+//! and the puzzle site client. Each year's solutions live in their own crate, which
+//! declares its solvers with the `solutions!` macro, or are run as external scripts.
+
+pub mod alloc;
+pub mod answer;
+pub mod bench;
+pub mod client;
+pub mod example;
+pub mod external;
+pub mod history;
+pub mod journal;
+pub mod parse;
+pub mod runner;
+pub mod scaffold;
+pub mod solver;
+pub mod submit;
+#[cfg(test)]
+mod test_utils;
+mod toml_file;
+pub mod utils;
+pub mod verify;
+pub mod watch;
+
+pub use answer::Answer;
+pub use solver::{DynSolver, Registry, Solver};
+pub use utils::InputSource;
+
+/// Register each day's solver, for use at the root of a year's solutions crate alongside a
+/// `YEAR` constant and the days' module declarations. The modules are declared as ordinary
+/// items rather than by the macro so that `cargo fmt` can reach them.
+///
+/// Also defines an `assert_examples` helper for the days' tests, which checks a solver
+/// against the example fixtures stored in the crate's `inputs/examples` directory.
+#[macro_export]
+macro_rules! solutions {
+    ($($module:ident::$solver:ident),* $(,)?) => {
+        /// Build the registry of every implemented solver.
+        pub fn registry() -> $crate::solver::Registry {
+            let mut registry = $crate::solver::Registry::new(YEAR, env!("CARGO_MANIFEST_DIR"));
+            $(registry.register($module::$solver);)*
+            registry
+        }
+
+        /// Assert that the given solver produces the expected answer for every one of its
+        /// examples.
+        #[cfg(test)]
+        fn assert_examples(solver: &dyn $crate::solver::DynSolver) {
+            $crate::example::assert_examples(solver, &registry().examples_dir());
+        }
+    };
+}
@@ -0,0 +1,156 @@
+use std::{fmt, str::FromStr};
+
+/// An error encountered while parsing puzzle input, pointing at the offending text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The name of the input the error was found in, if known
+    pub file: Option<String>,
+    /// 1-based line number
+    pub line: usize,
+    /// 1-based column number, counted in characters
+    pub column: usize,
+    /// Number of characters of offending text, starting at the column
+    pub len: usize,
+    /// The full text of the offending line
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Create an error for the given 0-based line index, spanning the given token, which
+    /// must be a slice of the line text. An empty token points just past the end of the line.
+    pub fn at(line_idx: usize, line: &str, token: &str, message: impl Into<String>) -> Self {
+        let offset = offset_in(line, token).unwrap_or(line.len());
+        ParseError {
+            file: None,
+            line: line_idx + 1,
+            column: line[..offset].chars().count() + 1,
+            len: token.chars().count(),
+            text: line.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// Create an error spanning the entirety of the given 0-based line index.
+    pub fn line(line_idx: usize, line: &str, message: impl Into<String>) -> Self {
+        Self::at(line_idx, line, line, message)
+    }
+
+    /// Create an error for input that ends before all expected contents were found.
+    pub fn end_of_input(lines: &[String], message: impl Into<String>) -> Self {
+        let line = lines.last().map_or("", String::as_str);
+        Self::at(lines.len().saturating_sub(1), line, "", message)
+    }
+
+    /// Attach the name of the input in which the error was found.
+    pub fn with_file(mut self, file: impl Into<String>) -> Self {
+        self.file = Some(file.into());
+        self
+    }
+
+    /// Render the error along with a snippet of the offending line, with carets
+    /// underlining the offending text.
+    pub fn render(&self) -> String {
+        let file = self.file.as_deref().unwrap_or("<input>");
+        let gutter = " ".repeat(self.line.to_string().len());
+        format!(
+            "error: {}\n{gutter}--> {file}:{}:{}\n{gutter} |\n{} | {}\n{gutter} | {}{}",
+            self.message,
+            self.line,
+            self.column,
+            self.line,
+            self.text,
+            " ".repeat(self.column - 1),
+            "^".repeat(self.len.max(1)),
+        )
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{file}:")?;
+        }
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Return the byte offset of the token within the line, if the token is a slice of the line.
+fn offset_in(line: &str, token: &str) -> Option<usize> {
+    let offset = (token.as_ptr() as usize).checked_sub(line.as_ptr() as usize)?;
+    (offset + token.len() <= line.len()).then_some(offset)
+}
+
+/// Parse the token, which must be a slice of the line at the given 0-based index, into a
+/// value of the requested type, producing an error pointing at the token if this fails.
+pub fn parse_token<T: FromStr>(line_idx: usize, line: &str, token: &str) -> Result<T, ParseError> {
+    token.parse().map_err(|_| {
+        ParseError::at(
+            line_idx,
+            line,
+            token,
+            format!("Invalid {} '{token}'", std::any::type_name::<T>()),
+        )
+    })
+}
+
+/// Convert the character at the given byte offset of the line into a decimal digit,
+/// producing an error pointing at the character if it is not a digit.
+pub fn parse_digit(line_idx: usize, line: &str, offset: usize) -> Result<u32, ParseError> {
+    let c = line[offset..]
+        .chars()
+        .next()
+        .expect("Offset should be within the line");
+    c.to_digit(10).ok_or_else(|| {
+        ParseError::at(
+            line_idx,
+            line,
+            &line[offset..offset + c.len_utf8()],
+            format!("Expected a digit, found '{c}'"),
+        )
+    })
+}
+
+/// Check that the grid parsed from the given lines is non-empty and that every row has
+/// the same length as the first, producing an error pointing at the first row that does not.
+pub fn check_rectangular<T>(lines: &[String], grid: &[Vec<T>]) -> Result<(), ParseError> {
+    let Some(width) = grid.first().map(Vec::len).filter(|&width| width > 0) else {
+        return Err(ParseError::end_of_input(lines, "Expected a non-empty grid"));
+    };
+    match grid.iter().position(|row| row.len() != width) {
+        Some(i) => Err(ParseError::line(
+            i,
+            &lines[i],
+            format!(
+                "Expected a row of width {width}, found width {}",
+                grid[i].len()
+            ),
+        )),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn points_at_token() {
+        let line = "12   x4";
+        let err = parse_token::<u32>(2, line, &line[5..]).unwrap_err();
+        assert_eq!((err.line, err.column, err.len), (3, 6, 2));
+        assert_eq!(
+            err.with_file("day01.txt").render(),
+            "error: Invalid u32 'x4'\n --> day01.txt:3:6\n  |\n3 | 12   x4\n  |      ^^"
+        );
+    }
+
+    #[test]
+    fn points_past_end_of_input() {
+        let lines = vec!["1 2".to_string()];
+        let err = ParseError::end_of_input(&lines, "Missing value");
+        assert_eq!((err.line, err.column, err.len), (1, 4, 0));
+    }
+}
@@ -0,0 +1,720 @@
+use crate::{
+    alloc::{self, format_bytes, AllocStats},
+    answer::Answer,
+    client,
+    example::{parse_example, Example, ExampleResult},
+    solver::{DynSolver, Registry},
+    utils::InputSource,
+};
+use std::{
+    any::Any,
+    cell::RefCell,
+    fmt,
+    num::NonZeroUsize,
+    panic::{self, AssertUnwindSafe},
+    path::Path,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc::{self, RecvTimeoutError},
+        Arc, Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+/// How long a solver may run in a batch before it is abandoned, unless configured otherwise
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// The number of threads abandoned after running past their time limit that are still running
+static ABANDONED: AtomicUsize = AtomicUsize::new(0);
+
+/// State shared between a thread run with a time limit and the caller waiting for it
+#[derive(Default)]
+struct TaskState {
+    /// Whether the caller has stopped waiting for the thread
+    abandoned: bool,
+    /// Stops work started by the thread that would otherwise outlive it, run by the caller
+    /// when it abandons the thread
+    cancel: Option<Box<dyn FnOnce() + Send>>,
+}
+
+thread_local! {
+    /// The state of the task run on the current thread by `run_with_timeout`, if any
+    static CURRENT_TASK: RefCell<Option<Arc<Mutex<TaskState>>>> = const { RefCell::new(None) };
+}
+
+/// The reason a solver failed to produce an answer in a batch run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RunError {
+    /// The solver returned an error, such as for an unreadable input
+    Failed(String),
+    /// The solver panicked with the given message
+    Panicked(String),
+    /// The solver was still running when the time limit expired
+    TimedOut(Duration),
+}
+
+impl RunError {
+    /// Return the status shown for the failure in result tables.
+    pub fn status(&self) -> &'static str {
+        match self {
+            RunError::Failed(_) => "FAILED",
+            RunError::Panicked(_) => "PANIC",
+            RunError::TimedOut(_) => "TIMEOUT",
+        }
+    }
+}
+
+impl fmt::Display for RunError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RunError::Failed(msg) | RunError::Panicked(msg) => write!(f, "{msg}"),
+            RunError::TimedOut(limit) => {
+                write!(f, "still running after {}", format_duration(*limit))
+            }
+        }
+    }
+}
+
+/// The result of running the solver for a single day and part.
+pub struct RunResult {
+    pub day: u32,
+    pub part: u32,
+    pub outcome: Result<Answer, RunError>,
+    pub elapsed: Duration,
+    /// Heap usage of the run, if the counting allocator is installed
+    pub alloc: Option<AllocStats>,
+}
+
+/// Run the solvers for every registered day within the given days, for each of the given parts,
+/// spreading the independent days over the given number of worker threads. Results are
+/// returned in order of day and part. A solver that fails, whether by returning an error,
+/// panicking or running past the given time limit, is recorded as a failure and does not
+/// prevent the remaining solvers from running.
+pub fn run_batch(
+    registry: &Registry,
+    days: impl Iterator<Item = u32>,
+    parts: &[u32],
+    source: &InputSource,
+    jobs: usize,
+    timeout: Option<Duration>,
+) -> Vec<RunResult> {
+    let days: Vec<u32> = days.filter(|&day| registry.get(day).is_some()).collect();
+
+    quiet_panics(|| {
+        parallel_map(&days, jobs, |&day| {
+            let solver = registry
+                .shared(day)
+                .expect("Task should be for a registered day");
+            run_day(solver, day, parts, source, timeout)
+        })
+        .into_iter()
+        .flatten()
+        .collect()
+    })
+}
+
+/// Read and parse the day's input once, then solve each of the given parts on it. Parsing
+/// and each part run on threads of their own, each timed and given the time limit separately.
+/// The time and heap usage of reading and parsing the input are counted towards the first part.
+fn run_day(
+    solver: Arc<dyn DynSolver>,
+    day: u32,
+    parts: &[u32],
+    source: &InputSource,
+    timeout: Option<Duration>,
+) -> Vec<RunResult> {
+    let parsed: Stage<Arc<dyn Any + Send + Sync>> = {
+        let (solver, source) = (Arc::clone(&solver), source.clone());
+        run_stage(format!("day{day:02}-parse"), timeout, move || {
+            solver.read_and_parse(&source).map(Arc::from)
+        })
+    };
+    let mut parse_cost = Some((parsed.elapsed, parsed.alloc));
+
+    parts
+        .iter()
+        .map(|&part| {
+            let solved = match &parsed.outcome {
+                Ok(input) => {
+                    let (solver, input) = (Arc::clone(&solver), Arc::clone(input));
+                    run_stage(format!("day{day:02}-part{part}"), timeout, move || {
+                        solver.solve(part, input.as_ref())
+                    })
+                }
+                Err(err) => Stage {
+                    outcome: Err(err.clone()),
+                    elapsed: Duration::ZERO,
+                    alloc: None,
+                },
+            };
+            let (parse_elapsed, parse_alloc) = parse_cost.take().unwrap_or_default();
+            RunResult {
+                day,
+                part,
+                outcome: solved.outcome,
+                elapsed: parse_elapsed + solved.elapsed,
+                alloc: match (parse_alloc, solved.alloc) {
+                    (Some(parse), Some(solve)) => Some(parse.then(&solve)),
+                    (parse, solve) => parse.or(solve),
+                },
+            }
+        })
+        .collect()
+}
+
+/// The outcome of a single stage of a run, along with its running time and heap usage
+struct Stage<T> {
+    outcome: Result<T, RunError>,
+    elapsed: Duration,
+    alloc: Option<AllocStats>,
+}
+
+/// Run the function with the given time limit as described for `run_with_timeout`, timing it
+/// and measuring its heap usage on the thread that runs it.
+fn run_stage<T: Send + 'static>(
+    name: String,
+    timeout: Option<Duration>,
+    f: impl FnOnce() -> Result<T, String> + Send + 'static,
+) -> Stage<T> {
+    let started = Instant::now();
+    let outcome = run_with_timeout(name, timeout, move || {
+        let start = Instant::now();
+        let (outcome, alloc) = alloc::measure(f);
+        (outcome, start.elapsed(), alloc)
+    });
+
+    match outcome {
+        Ok((outcome, elapsed, alloc)) => Stage {
+            outcome: outcome.map_err(RunError::Failed),
+            elapsed,
+            alloc,
+        },
+        Err(err) => Stage {
+            outcome: Err(err),
+            elapsed: started.elapsed(),
+            alloc: None,
+        },
+    }
+}
+
+/// Run the function on a new thread with the given name, catching any panic. If it is still
+/// running once the time limit expires, its thread is abandoned to run on in the background,
+/// as threads cannot be stopped from outside, and is counted by `abandoned_threads` until it
+/// finishes. Work it started elsewhere can be stopped by registering it with `on_abandon`.
+pub fn run_with_timeout<T: Send + 'static>(
+    name: String,
+    timeout: Option<Duration>,
+    f: impl FnOnce() -> T + Send + 'static,
+) -> Result<T, RunError> {
+    let (sender, receiver) = mpsc::channel();
+    let task = Arc::new(Mutex::new(TaskState::default()));
+    let thread_task = Arc::clone(&task);
+    thread::Builder::new()
+        .name(name)
+        .spawn(move || {
+            CURRENT_TASK.with(|task| *task.borrow_mut() = Some(Arc::clone(&thread_task)));
+            let result = panic::catch_unwind(AssertUnwindSafe(f))
+                .map_err(|payload| RunError::Panicked(panic_message(&payload)));
+            let state = thread_task.lock().unwrap();
+            if state.abandoned {
+                ABANDONED.fetch_sub(1, Ordering::Relaxed);
+            } else {
+                let _ = sender.send(result);
+            }
+        })
+        .map_err(|err| RunError::Failed(format!("Cannot start thread: {err}")))?;
+
+    let received = match timeout {
+        Some(timeout) => receiver.recv_timeout(timeout),
+        None => receiver.recv().map_err(RecvTimeoutError::from),
+    };
+    match received {
+        Ok(result) => result,
+        Err(RecvTimeoutError::Timeout) => {
+            let mut state = task.lock().unwrap();
+            // The thread may have finished just after the time limit expired
+            if let Ok(result) = receiver.try_recv() {
+                return result;
+            }
+            state.abandoned = true;
+            ABANDONED.fetch_add(1, Ordering::Relaxed);
+            let cancel = state.cancel.take();
+            drop(state);
+            if let Some(cancel) = cancel {
+                cancel();
+            }
+            Err(RunError::TimedOut(
+                timeout.expect("Only a time limit can expire"),
+            ))
+        }
+        Err(RecvTimeoutError::Disconnected) => Err(RunError::Panicked(
+            "Thread exited without a result".to_string(),
+        )),
+    }
+}
+
+/// Run the given action if the current thread is abandoned by `run_with_timeout` before the
+/// returned guard is dropped, such as to stop a child process that would otherwise keep
+/// running. The action is run by the caller as it abandons the thread, or at once if the
+/// thread was abandoned already, and never outside of `run_with_timeout`.
+pub fn on_abandon(cancel: impl FnOnce() + Send + 'static) -> CancelGuard {
+    let task = CURRENT_TASK.with(|task| task.borrow().clone());
+    if let Some(task) = &task {
+        let mut state = task.lock().unwrap();
+        if state.abandoned {
+            drop(state);
+            cancel();
+        } else {
+            state.cancel = Some(Box::new(cancel));
+        }
+    }
+    CancelGuard(task)
+}
+
+/// Withdraws the action registered by `on_abandon` when dropped.
+pub struct CancelGuard(Option<Arc<Mutex<TaskState>>>);
+
+impl Drop for CancelGuard {
+    fn drop(&mut self) {
+        if let Some(task) = &self.0 {
+            task.lock().unwrap().cancel = None;
+        }
+    }
+}
+
+/// Return the number of threads abandoned after running past their time limit that are
+/// still running in the background.
+pub fn abandoned_threads() -> usize {
+    ABANDONED.load(Ordering::Relaxed)
+}
+
+/// Return the number of worker threads to use by default, one per available core.
+pub fn default_jobs() -> usize {
+    thread::available_parallelism().map_or(1, NonZeroUsize::get)
+}
+
+/// Apply the function to every item using a pool of the given number of scoped threads,
+/// each taking the next unprocessed item until none remain. Results are returned in the
+/// order of the items they were produced from.
+pub fn parallel_map<T: Sync, R: Send>(
+    items: &[T],
+    jobs: usize,
+    f: impl Fn(&T) -> R + Sync,
+) -> Vec<R> {
+    let next = AtomicUsize::new(0);
+    let results: Vec<Mutex<Option<R>>> = items.iter().map(|_| Mutex::new(None)).collect();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, items.len().max(1)) {
+            scope.spawn(|| loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(i) else {
+                    break;
+                };
+                let result = f(item);
+                *results[i].lock().unwrap() = Some(result);
+            });
+        }
+    });
+
+    results
+        .into_iter()
+        .map(|result| {
+            result
+                .into_inner()
+                .unwrap()
+                .expect("Every item should have been processed")
+        })
+        .collect()
+}
+
+/// Solve the examples declared by every registered solver within the given days, keeping
+/// only the results for the given parts. Each example is parsed once, then each part is
+/// solved on a thread of its own, and abandoned if it runs past the given time limit.
+pub fn run_examples_batch(
+    registry: &Registry,
+    days: impl Iterator<Item = u32>,
+    parts: &[u32],
+    timeout: Option<Duration>,
+) -> Vec<(u32, Vec<ExampleResult>)> {
+    let examples_dir = registry.examples_dir();
+    quiet_panics(|| {
+        days.filter_map(|day| registry.shared(day).map(|solver| (day, solver)))
+            .map(|(day, solver)| {
+                let results = solver
+                    .examples()
+                    .iter()
+                    .flat_map(|example| {
+                        run_example_parts(&solver, day, example, &examples_dir, parts, timeout)
+                    })
+                    .collect();
+                (day, results)
+            })
+            .collect()
+    })
+}
+
+/// Parse the example and solve each of the given parts that has an expected answer, with
+/// parsing and each part running on threads of their own with the given time limit, so
+/// that a part that fails does not affect the outcome of the others.
+fn run_example_parts(
+    solver: &Arc<dyn DynSolver>,
+    day: u32,
+    example: &Example,
+    examples_dir: &Path,
+    parts: &[u32],
+    timeout: Option<Duration>,
+) -> Vec<ExampleResult> {
+    if parts.iter().all(|&part| example.expected(part).is_none()) {
+        return Vec::new();
+    }
+
+    let name = format!("day{day:02}-{}", example.file);
+    let parsed: Result<Arc<dyn Any + Send + Sync>, String> = {
+        let (solver, task) = (Arc::clone(solver), example.clone());
+        let examples_dir = examples_dir.to_path_buf();
+        run_stage(name.clone(), timeout, move || {
+            parse_example(solver.as_ref(), &task, &examples_dir).map(Arc::from)
+        })
+        .outcome
+        .map_err(example_error)
+    };
+
+    example.results(parts, |part| {
+        let input = Arc::clone(parsed.as_ref().map_err(Clone::clone)?);
+        let solver = Arc::clone(solver);
+        run_stage(format!("{name}-part{part}"), timeout, move || {
+            solver.solve(part, input.as_ref())
+        })
+        .outcome
+        .map_err(example_error)
+    })
+}
+
+/// Describe the failure of an example, naming how it failed unless the solver reported it.
+fn example_error(err: RunError) -> String {
+    match err {
+        RunError::Failed(msg) => msg,
+        RunError::Panicked(msg) => format!("panicked: {msg}"),
+        RunError::TimedOut(_) => format!("{}: {err}", err.status()),
+    }
+}
+
+/// The panic hook, which prints the message of each panic
+type PanicHook = Box<dyn Fn(&panic::PanicHookInfo<'_>) + Sync + Send>;
+
+/// Reinstates the panic hook it holds when dropped, including while unwinding.
+struct RestoreHook(Option<PanicHook>);
+
+impl Drop for RestoreHook {
+    fn drop(&mut self) {
+        if let Some(hook) = self.0.take() {
+            panic::set_hook(hook);
+        }
+    }
+}
+
+/// Run the given function with the default panic message silenced, for use when
+/// panics are caught and reported by the caller instead. The previous panic hook is
+/// restored afterwards, even if the function itself panics.
+pub fn quiet_panics<R>(f: impl FnOnce() -> R) -> R {
+    let _restore = RestoreHook(Some(panic::take_hook()));
+    panic::set_hook(Box::new(|_| {}));
+    f()
+}
+
+/// Run the given fallible function, converting a panic into an error describing it.
+pub fn isolated<T>(f: impl FnOnce() -> Result<T, String>) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(f))
+        .unwrap_or_else(|payload| Err(format!("panicked: {}", panic_message(&payload))))
+}
+
+/// Extract the message from a panic payload, which is either a &str or a String.
+fn panic_message(payload: &Box<dyn Any + Send>) -> String {
+    if let Some(msg) = payload.downcast_ref::<&str>() {
+        msg.to_string()
+    } else if let Some(msg) = payload.downcast_ref::<String>() {
+        msg.clone()
+    } else {
+        "unknown panic payload".to_string()
+    }
+}
+
+/// Solve a single day and part, printing the answer in full. Offers to download the day's
+/// input first if it is missing, and reports parse errors with their location in the input.
+pub fn solve_single(
+    registry: &Registry,
+    day: u32,
+    part: u32,
+    source: &InputSource,
+) -> Result<(), String> {
+    let Some(solver) = registry.get(day) else {
+        let available: Vec<String> = registry.days().map(|day| day.to_string()).collect();
+        return Err(format!(
+            "No solver registered for day {day}. Available days: {}",
+            available.join(", ")
+        ));
+    };
+
+    println!("Solving day: {day:?}, part: {part:?}");
+
+    let path = registry.input_path(day);
+    if *source == registry.default_input() && !client::is_cached(&path) {
+        client::offer_fetch(registry.year(), day, &path);
+    }
+
+    // Parse the input separately so that parse errors can be shown in full
+    let lines = solver.read_input(source)?;
+    let input = solver
+        .parse(&lines)
+        .map_err(|err| err.with_file(source.describe(day)).render())?;
+
+    // Call the solver for requested day and part
+    match solver.solve(part, input.as_ref())? {
+        Answer::Grid(rows) => println!("Answer:\n{}", rows.join("\n")),
+        answer => println!("Answer: {answer}"),
+    }
+    Ok(())
+}
+
+/// Print a table of the provided results with one row per day and part.
+pub fn print_summary(results: &[RunResult]) {
+    let rows: Vec<(String, String)> = results
+        .iter()
+        .map(|result| {
+            let answer = match &result.outcome {
+                Ok(Answer::Grid(rows)) => format!("<grid with {} rows>", rows.len()),
+                Ok(answer) => answer.to_string(),
+                Err(err) => format!("{}: {err}", err.status()),
+            };
+            (answer, format_duration(result.elapsed))
+        })
+        .collect();
+
+    let answer_width = rows
+        .iter()
+        .map(|(answer, _)| answer.chars().count())
+        .chain(["Answer".len()])
+        .max()
+        .unwrap_or_default();
+
+    // Heap usage is only shown when the counting allocator is installed
+    let show_alloc = results.iter().any(|result| result.alloc.is_some());
+    let header = format!(
+        "{:>3}  {:>4}  {:<answer_width$}  {:>10}",
+        "Day", "Part", "Answer", "Time"
+    );
+    if show_alloc {
+        println!(
+            "{header}  {}",
+            alloc_columns(["Peak", "Allocated", "Allocs"])
+        );
+    } else {
+        println!("{header}");
+    }
+    for (result, (answer, time)) in results.iter().zip(rows) {
+        let row = format!(
+            "{:>3}  {:>4}  {:<answer_width$}  {:>10}",
+            result.day, result.part, answer, time
+        );
+        match result.alloc {
+            Some(stats) if show_alloc => println!("{row}  {}", format_alloc(&stats)),
+            _ => println!("{row}"),
+        }
+    }
+
+    let num_failed = results.iter().filter(|r| r.outcome.is_err()).count();
+    let total: Duration = results.iter().map(|r| r.elapsed).sum();
+    println!(
+        "\n{} solved, {} failed, total time {}",
+        results.len() - num_failed,
+        num_failed,
+        format_duration(total)
+    );
+}
+
+/// Lay out the given values in the heap usage columns of a table.
+pub fn alloc_columns(values: [&str; 3]) -> String {
+    format!("{:>10}  {:>10}  {:>10}", values[0], values[1], values[2])
+}
+
+/// Format heap usage statistics as the heap usage columns of a table.
+pub fn format_alloc(stats: &AllocStats) -> String {
+    alloc_columns([
+        &format_bytes(stats.peak_bytes as u64),
+        &format_bytes(stats.total_bytes),
+        &stats.count.to_string(),
+    ])
+}
+
+/// Print a table of the provided example results for each day, including days that
+/// do not declare any examples.
+pub fn print_examples(results: &[(u32, Vec<ExampleResult>)]) {
+    println!(
+        "{:>3}  {:>4}  {:<16}  {:<6}  Details",
+        "Day", "Part", "Example", "Status"
+    );
+    for (day, day_results) in results {
+        if day_results.is_empty() {
+            println!("{day:>3}  {:>4}  {:<16}  NONE", "-", "-");
+        }
+        for result in day_results {
+            let (status, details) = match &result.outcome {
+                _ if result.passed() => ("PASS", String::new()),
+                Ok(actual) => (
+                    "FAIL",
+                    format!("expected {}, got {actual}", result.expected),
+                ),
+                Err(err) => ("FAIL", err.clone()),
+            };
+            let row = format!(
+                "{day:>3}  {:>4}  {:<16}  {status:<6}  {details}",
+                result.part, result.file
+            );
+            println!("{}", row.trim_end());
+        }
+    }
+}
+
+/// Format a duration with a unit appropriate to its magnitude.
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    if nanos < 1_000 {
+        format!("{nanos} ns")
+    } else if nanos < 1_000_000 {
+        format!("{:.1} µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.2} ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2} s", nanos as f64 / 1e9)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{example::Example, parse::ParseError, solver::Solver, test_utils::TempDir};
+    use std::fs;
+
+    #[test]
+    fn parallel_map_keeps_item_order() {
+        let items: Vec<u64> = (0..50).collect();
+        let results = parallel_map(&items, 4, |&n| {
+            // Finish later items first to shuffle the order in which results arrive
+            thread::sleep(Duration::from_micros(50 - n));
+            n * n
+        });
+        assert_eq!(results, items.iter().map(|n| n * n).collect::<Vec<_>>());
+        assert!(parallel_map(&[] as &[u64], 4, |&n| n).is_empty());
+    }
+
+    struct Faulty;
+
+    impl Solver for Faulty {
+        const DAY: u32 = 1;
+        type Input = ();
+
+        fn parse_input(&self, _lines: &[String]) -> Result<(), ParseError> {
+            Ok(())
+        }
+
+        fn solve_part_1(&self, _input: &()) -> Answer {
+            panic!("Bad input");
+        }
+
+        fn solve_part_2(&self, _input: &()) -> Answer {
+            loop {
+                thread::sleep(Duration::from_millis(10));
+            }
+        }
+
+        fn examples(&self) -> Vec<Example> {
+            vec![Example::new("day01.txt").part_1(1).part_2(2)]
+        }
+    }
+
+    struct Working;
+
+    impl Solver for Working {
+        const DAY: u32 = 2;
+        type Input = usize;
+
+        fn parse_input(&self, lines: &[String]) -> Result<usize, ParseError> {
+            Ok(lines.len())
+        }
+
+        fn solve_part_1(&self, input: &usize) -> Answer {
+            Answer::Int(*input as i64)
+        }
+
+        fn solve_part_2(&self, input: &usize) -> Answer {
+            Answer::Int(*input as i64 * 2)
+        }
+    }
+
+    #[test]
+    fn isolates_panicking_and_hanging_solvers() {
+        let dir = TempDir::new("runner");
+        let mut registry = Registry::new(2024, dir.to_path_buf());
+        registry.register(Faulty);
+        registry.register(Working);
+        for day in [1, 2] {
+            fs::create_dir_all(registry.inputs_dir()).unwrap();
+            fs::write(registry.input_path(day), "a\nb\nc\n").unwrap();
+        }
+
+        let timeout = Duration::from_millis(200);
+        let results = run_batch(
+            &registry,
+            1..=2,
+            &[1, 2],
+            &registry.default_input(),
+            2,
+            Some(timeout),
+        );
+        let outcomes: Vec<_> = results.into_iter().map(|result| result.outcome).collect();
+        assert_eq!(
+            outcomes,
+            vec![
+                Err(RunError::Panicked("Bad input".to_string())),
+                Err(RunError::TimedOut(timeout)),
+                Ok(Answer::Int(3)),
+                Ok(Answer::Int(6)),
+            ]
+        );
+
+        fs::create_dir_all(registry.examples_dir()).unwrap();
+        fs::write(registry.examples_dir().join("day01.txt"), "a\n").unwrap();
+        let examples = run_examples_batch(&registry, 1..=1, &[1, 2], Some(timeout));
+        let outcomes: Vec<_> = examples[0].1.iter().map(|r| r.outcome.clone()).collect();
+        assert_eq!(
+            outcomes,
+            vec![
+                Err("panicked: Bad input".to_string()),
+                Err("TIMEOUT: still running after 200.00 ms".to_string()),
+            ]
+        );
+        let bench = crate::bench::run_bench(
+            &registry,
+            1..=1,
+            &[1, 2],
+            &registry.default_input(),
+            1,
+            Some(timeout),
+        );
+        let statuses: Vec<_> = bench
+            .iter()
+            .map(|result| result.outcome.as_ref().map_err(RunError::status).err())
+            .collect();
+        assert_eq!(statuses, vec![Some("PANIC"), Some("TIMEOUT")]);
+        assert!(abandoned_threads() >= 3);
+
+        // The part that hangs is not solved unless selected
+        let examples = run_examples_batch(&registry, 1..=1, &[1], None);
+        assert_eq!(
+            examples[0].1[0].outcome,
+            Err("panicked: Bad input".to_string())
+        );
+    }
+}
@@ -0,0 +1,184 @@
+use crate::solver::Registry;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// The source of a new day's module, with placeholders for the day number
+const TEMPLATE: &str = include_str!("../templates/day.rs.tmpl");
+
+/// Generate the module for a new day of the registry's year from the template, declare it
+/// and add it to the `solutions!` invocation at the root of the year's crate, then create
+/// empty input and example files for it unless they already exist. Fail without changing
+/// anything if the day's module already exists. Return the files that were created or
+/// changed.
+pub fn scaffold_day(registry: &Registry, day: u32) -> Result<Vec<PathBuf>, String> {
+    let source_dir = registry.source_dir();
+    let module_path = source_dir.join(format!("day{day:02}.rs"));
+    if module_path.exists() {
+        return Err(format!(
+            "Day {day} already exists at {}",
+            module_path.display()
+        ));
+    }
+    let mod_path = source_dir.join("lib.rs");
+    let mod_contents = fs::read_to_string(&mod_path)
+        .map_err(|err| format!("Cannot read {}: {err}", mod_path.display()))?;
+    let mod_contents = register_module(&mod_contents, day)
+        .map_err(|err| format!("Cannot update {}: {err}", mod_path.display()))?;
+
+    let module = TEMPLATE
+        .replace("{{DAY_PADDED}}", &format!("{day:02}"))
+        .replace("{{DAY}}", &day.to_string());
+    write_file(&module_path, &module)?;
+    write_file(&mod_path, &mod_contents)?;
+
+    let mut changed = vec![module_path, mod_path];
+    for path in [
+        registry.examples_dir().join(format!("day{day:02}.txt")),
+        registry.input_path(day),
+    ] {
+        if !path.exists() {
+            write_file(&path, "")?;
+            changed.push(path);
+        }
+    }
+    Ok(changed)
+}
+
+fn write_file(path: &Path, contents: &str) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|err| format!("Cannot create {}: {err}", dir.display()))?;
+    }
+    fs::write(path, contents).map_err(|err| format!("Cannot write {}: {err}", path.display()))
+}
+
+/// Declare the given day's module in the contents of the crate root and add it to the
+/// entries of the `solutions!` invocation, keeping both ordered by day.
+fn register_module(contents: &str, day: u32) -> Result<String, String> {
+    let contents = declare_module(contents, day)?;
+    add_solutions_entry(&contents, day)
+}
+
+/// Add the declaration of the given day's module among the existing declarations, or just
+/// before the `solutions!` invocation if there are none.
+fn declare_module(contents: &str, day: u32) -> Result<String, String> {
+    let declaration = format!("pub mod day{day:02};");
+    let mut lines: Vec<&str> = contents.lines().collect();
+    if lines.contains(&declaration.as_str()) {
+        return Err(format!("Day {day} is already declared"));
+    }
+
+    let is_declaration = |line: &&str| line.starts_with("pub mod day");
+    let index = match lines.iter().rposition(is_declaration) {
+        // Zero-padded day numbers sort in the same order as the days themselves
+        Some(last) => lines[..=last]
+            .iter()
+            .position(|line| is_declaration(line) && *line > declaration.as_str())
+            .unwrap_or(last + 1),
+        None => {
+            let index = lines
+                .iter()
+                .position(|line| line.starts_with("solutions!"))
+                .ok_or("No solutions! invocation found")?;
+            lines.insert(index, "");
+            index
+        }
+    };
+    lines.insert(index, &declaration);
+
+    let mut contents = lines.join("\n");
+    contents.push('\n');
+    Ok(contents)
+}
+
+/// Add the given day to the entries of the `solutions!` invocation.
+fn add_solutions_entry(contents: &str, day: u32) -> Result<String, String> {
+    let invocation = "solutions! {\n";
+    let start = contents
+        .find(invocation)
+        .map(|i| i + invocation.len())
+        .ok_or("No solutions! invocation found")?;
+    let end = start
+        + contents[start..]
+            .find('}')
+            .ok_or("Unterminated solutions! invocation")?;
+
+    let entry = format!("day{day:02}::Day{day:02}");
+    let mut entries: Vec<&str> = contents[start..end]
+        .lines()
+        .map(|line| line.trim().trim_end_matches(','))
+        .filter(|line| !line.is_empty())
+        .collect();
+    if entries.contains(&entry.as_str()) {
+        return Err(format!("Day {day} is already registered"));
+    }
+    entries.push(&entry);
+    entries.sort_unstable();
+
+    let entries: String = entries
+        .iter()
+        .map(|entry| format!("    {entry},\n"))
+        .collect();
+    Ok(format!(
+        "{}{entries}{}",
+        &contents[..start],
+        &contents[end..]
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::TempDir;
+
+    #[test]
+    fn scaffolds_new_day() {
+        let dir = TempDir::new("scaffold");
+        let registry = Registry::new(2024, dir.to_path_buf());
+        let source = registry.source_dir();
+        let examples = registry.examples_dir();
+        let input = registry.input_path(12);
+        fs::create_dir_all(&source).unwrap();
+        fs::write(
+            source.join("lib.rs"),
+            "pub const YEAR: u32 = 2024;\n\npub mod day01;\npub mod day13;\n\nsolutions! {\n    day01::Day01,\n    day13::Day13,\n}\n",
+        )
+        .unwrap();
+        fs::create_dir_all(&examples).unwrap();
+        fs::write(examples.join("day12.txt"), "1\n2\n").unwrap();
+
+        let changed = scaffold_day(&registry, 12).unwrap();
+        assert_eq!(
+            changed,
+            vec![
+                source.join("day12.rs"),
+                source.join("lib.rs"),
+                input.clone()
+            ]
+        );
+        assert_eq!(
+            fs::read_to_string(source.join("lib.rs")).unwrap(),
+            "pub const YEAR: u32 = 2024;\n\npub mod day01;\npub mod day12;\npub mod day13;\n\nsolutions! {\n    day01::Day01,\n    day12::Day12,\n    day13::Day13,\n}\n"
+        );
+        let module = fs::read_to_string(source.join("day12.rs")).unwrap();
+        assert!(module.contains("impl Solver for Day12 {\n    const DAY: u32 = 12;"));
+        assert!(!module.contains("{{"));
+        // Existing example contents are left alone
+        assert_eq!(
+            fs::read_to_string(examples.join("day12.txt")).unwrap(),
+            "1\n2\n"
+        );
+        assert_eq!(fs::read_to_string(&input).unwrap(), "");
+
+        // Scaffolding the same day again is refused
+        let err = scaffold_day(&registry, 12).unwrap_err();
+        assert!(err.contains("already exists"), "{err}");
+
+        // The first day of a year is declared just before the invocation
+        assert_eq!(
+            register_module("solutions! {\n}\n", 1).unwrap(),
+            "pub mod day01;\n\nsolutions! {\n    day01::Day01,\n}\n"
+        );
+    }
+}
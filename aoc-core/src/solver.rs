@@ -0,0 +1,213 @@
+use crate::{
+    answer::Answer,
+    example::Example,
+    parse::ParseError,
+    utils::{default_input_path, InputSource},
+};
+use std::{any::Any, collections::BTreeMap, path::PathBuf, sync::Arc};
+
+/// A solution to a single day of the puzzle calendar. Each day parses its input once
+/// into a day-specific representation, which is then shared by both parts. Solvers may be
+/// run from several threads at once.
+pub trait Solver: Send + Sync {
+    /// The day of the calendar that this solver solves
+    const DAY: u32;
+
+    /// The parsed representation of the puzzle input, which is shared by the threads
+    /// solving each part
+    type Input: Send + Sync;
+
+    fn parse_input(&self, lines: &[String]) -> Result<Self::Input, ParseError>;
+
+    fn solve_part_1(&self, input: &Self::Input) -> Answer;
+
+    fn solve_part_2(&self, input: &Self::Input) -> Answer;
+
+    /// The worked examples from the puzzle statement, checked by each day's tests
+    fn examples(&self) -> Vec<Example> {
+        Vec::new()
+    }
+}
+
+/// A solver's parsed input with its type erased, which can be shared between threads
+pub type ParsedInput = Box<dyn Any + Send + Sync>;
+
+/// Object-safe view of a `Solver`, allowing solvers with different input types
+/// to be stored side by side in the registry.
+pub trait DynSolver: Send + Sync {
+    fn day(&self) -> u32;
+
+    /// Parse the lines of puzzle input into the solver's type-erased input representation.
+    fn parse(&self, lines: &[String]) -> Result<ParsedInput, ParseError>;
+
+    /// Solve the requested part using input previously returned by `parse`.
+    /// Return an error if the part does not exist.
+    fn solve(&self, part: u32, input: &dyn Any) -> Result<Answer, String>;
+
+    fn examples(&self) -> Vec<Example>;
+
+    /// Read all lines of this solver's puzzle input from the given source.
+    fn read_input(&self, source: &InputSource) -> Result<Vec<String>, String> {
+        source.read_lines(self.day()).map_err(|err| {
+            format!(
+                "Cannot read input from {}: {err}",
+                source.describe(self.day())
+            )
+        })
+    }
+
+    /// Read and parse the input from the given source, naming the source in any parse error.
+    fn read_and_parse(&self, source: &InputSource) -> Result<ParsedInput, String> {
+        let lines = self.read_input(source)?;
+        self.parse(&lines)
+            .map_err(|err| err.with_file(source.describe(self.day())).to_string())
+    }
+
+    /// Read and parse the input from the given source, then solve the requested part.
+    /// Return an error if the part does not exist or the input cannot be read or parsed.
+    fn run(&self, part: u32, source: &InputSource) -> Result<Answer, String> {
+        let input = self.read_and_parse(source)?;
+        self.solve(part, input.as_ref())
+    }
+}
+
+impl<S: Solver> DynSolver for S
+where
+    S::Input: 'static,
+{
+    fn day(&self) -> u32 {
+        S::DAY
+    }
+
+    fn parse(&self, lines: &[String]) -> Result<ParsedInput, ParseError> {
+        Ok(Box::new(self.parse_input(lines)?))
+    }
+
+    fn solve(&self, part: u32, input: &dyn Any) -> Result<Answer, String> {
+        let input = input
+            .downcast_ref::<S::Input>()
+            .expect("Input should have been parsed by the same solver");
+        match part {
+            1 => Ok(self.solve_part_1(input)),
+            2 => Ok(self.solve_part_2(input)),
+            _ => Err(format!("Day {} has no part {part}", S::DAY)),
+        }
+    }
+
+    fn examples(&self) -> Vec<Example> {
+        Solver::examples(self)
+    }
+}
+
+/// Collection of all available solvers for a single year, keyed by day, along with the
+/// directory of the year's crate, which holds its inputs, examples and recorded answers.
+pub struct Registry {
+    year: u32,
+    dir: PathBuf,
+    solvers: BTreeMap<u32, Arc<dyn DynSolver>>,
+}
+
+impl Registry {
+    pub fn new(year: u32, dir: impl Into<PathBuf>) -> Self {
+        Registry {
+            year,
+            dir: dir.into(),
+            solvers: BTreeMap::new(),
+        }
+    }
+
+    pub fn year(&self) -> u32 {
+        self.year
+    }
+
+    /// Return the directory containing the solution modules.
+    pub fn source_dir(&self) -> PathBuf {
+        self.dir.join("src")
+    }
+
+    /// Return the directory containing the puzzle inputs.
+    pub fn inputs_dir(&self) -> PathBuf {
+        self.dir.join("inputs")
+    }
+
+    /// Return the directory containing the example fixtures, which are part of the source tree.
+    pub fn examples_dir(&self) -> PathBuf {
+        self.dir.join("inputs/examples")
+    }
+
+    /// Return the directory in which accepted answers are stored.
+    pub fn answers_dir(&self) -> PathBuf {
+        self.dir.join("answers")
+    }
+
+    /// Return the directory in which submission state is kept, including the journal of
+    /// every submitted answer.
+    pub fn submissions_dir(&self) -> PathBuf {
+        self.dir.join("submissions")
+    }
+
+    /// Return the location of the history of bench runs, which is kept locally.
+    pub fn bench_history_path(&self) -> PathBuf {
+        self.dir.join("bench-history.toml")
+    }
+
+    /// Return the source reading each day's input file from the inputs directory.
+    pub fn default_input(&self) -> InputSource {
+        InputSource::Default(self.inputs_dir())
+    }
+
+    /// Return the location of the given day's input file in the inputs directory.
+    pub fn input_path(&self, day: u32) -> PathBuf {
+        default_input_path(&self.inputs_dir(), day)
+    }
+
+    /// Add a solver to the registry. Panics if a solver is already registered for the same day.
+    pub fn register<S: Solver + 'static>(&mut self, solver: S) {
+        self.register_dyn(Box::new(solver));
+    }
+
+    /// Add a type-erased solver, such as one running outside of this process, to the registry.
+    /// Panics if a solver is already registered for the same day.
+    pub fn register_dyn(&mut self, solver: Box<dyn DynSolver>) {
+        let day = solver.day();
+        if self.solvers.insert(day, Arc::from(solver)).is_some() {
+            panic!("Multiple solvers registered for day {day}");
+        }
+    }
+
+    pub fn get(&self, day: u32) -> Option<&dyn DynSolver> {
+        self.solvers.get(&day).map(|solver| solver.as_ref())
+    }
+
+    /// Return a shared handle to the solver for the given day, which can outlive the
+    /// registry, such as on a thread that is abandoned if it runs for too long.
+    pub fn shared(&self, day: u32) -> Option<Arc<dyn DynSolver>> {
+        self.solvers.get(&day).cloned()
+    }
+
+    /// Return the registered days in increasing order.
+    pub fn days(&self) -> impl Iterator<Item = u32> + '_ {
+        self.solvers.keys().copied()
+    }
+}
+
+/// Return the registry of solvers for the requested year, or for the latest year with
+/// solutions if none is requested.
+pub fn select_year(registries: Vec<Registry>, year: Option<u32>) -> Result<Registry, String> {
+    let Some(year) = year else {
+        return registries
+            .into_iter()
+            .max_by_key(Registry::year)
+            .ok_or_else(|| "No solutions for any year".to_string());
+    };
+    let available: Vec<String> = registries.iter().map(|r| r.year().to_string()).collect();
+    registries
+        .into_iter()
+        .find(|registry| registry.year() == year)
+        .ok_or_else(|| {
+            format!(
+                "No solutions for year {year}. Available years: {}",
+                available.join(", ")
+            )
+        })
+}
@@ -0,0 +1,361 @@
+use crate::{
+    answer::Answer,
+    client::AocClient,
+    journal::{Attempt, Journal},
+    runner::format_duration,
+    solver::Registry,
+};
+use regex::Regex;
+use std::{
+    fmt, fs,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// Whether a wrong answer was reported as too high or too low.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+/// The verdict of the puzzle site on a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubmitOutcome {
+    Correct,
+    Wrong {
+        hint: Option<Hint>,
+        /// How long the site asks to wait before the next submission
+        wait: Option<Duration>,
+    },
+    /// The answer was not checked because the previous submission was too recent
+    RateLimited {
+        wait: Duration,
+    },
+    /// The part has already been solved, or is not unlocked yet
+    WrongLevel,
+    /// The response page did not contain any known verdict; holds the text of the page
+    Unrecognized(String),
+}
+
+impl fmt::Display for SubmitOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SubmitOutcome::Correct => write!(f, "Correct!"),
+            SubmitOutcome::Wrong { hint, wait } => {
+                write!(f, "Wrong answer")?;
+                match hint {
+                    Some(Hint::TooHigh) => write!(f, ", too high")?,
+                    Some(Hint::TooLow) => write!(f, ", too low")?,
+                    None => {}
+                }
+                if let Some(wait) = wait {
+                    write!(f, " (wait {} before trying again)", format_duration(*wait))?;
+                }
+                Ok(())
+            }
+            SubmitOutcome::RateLimited { wait } => write!(
+                f,
+                "Rate limited, {} left to wait before submitting again",
+                format_duration(*wait)
+            ),
+            SubmitOutcome::WrongLevel => write!(
+                f,
+                "This part has already been solved or is not unlocked yet"
+            ),
+            SubmitOutcome::Unrecognized(text) => write!(f, "Unrecognized response: {text}"),
+        }
+    }
+}
+
+/// Determine the verdict from the response page of an answer submission.
+pub fn parse_response(page: &str) -> SubmitOutcome {
+    let text = page_text(page);
+    if text.contains("That's the right answer") {
+        SubmitOutcome::Correct
+    } else if text.contains("That's not the right answer") {
+        let hint = if text.contains("too high") {
+            Some(Hint::TooHigh)
+        } else if text.contains("too low") {
+            Some(Hint::TooLow)
+        } else {
+            None
+        };
+        SubmitOutcome::Wrong {
+            hint,
+            wait: parse_wait(&text),
+        }
+    } else if text.contains("You gave an answer too recently") {
+        SubmitOutcome::RateLimited {
+            // Fall back to the shortest delay the site imposes if the page does not say
+            wait: parse_wait(&text).unwrap_or(Duration::from_secs(60)),
+        }
+    } else if text.contains("You don't seem to be solving the right level") {
+        SubmitOutcome::WrongLevel
+    } else {
+        SubmitOutcome::Unrecognized(text)
+    }
+}
+
+/// Extract the text of the main article of the page, with tags removed and whitespace
+/// collapsed, or of the whole page if it has no article.
+fn page_text(page: &str) -> String {
+    let article = page
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once('>'))
+        .map_or(page, |(_, rest)| {
+            rest.split_once("</article>").map_or(rest, |(body, _)| body)
+        });
+    let tags = Regex::new(r"<[^>]*>").unwrap();
+    tags.replace_all(article, "")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Parse the waiting time stated on a response page, either as the time left until the
+/// next submission, such as "You have 1m 30s left to wait", or as the penalty for a wrong
+/// answer, such as "please wait 5 minutes before trying again".
+fn parse_wait(text: &str) -> Option<Duration> {
+    let left = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+    if let Some(captures) = left.captures(text) {
+        let minutes: u64 = captures.get(1).map_or(0, |m| m.as_str().parse().unwrap());
+        let seconds: u64 = captures[2].parse().unwrap();
+        return Some(Duration::from_secs(minutes * 60 + seconds));
+    }
+
+    let penalty = Regex::new(r"wait (one|\d+) minutes? before trying again").unwrap();
+    let minutes = match &penalty.captures(text)?[1] {
+        "one" => 1,
+        n => n.parse().unwrap(),
+    };
+    Some(Duration::from_secs(minutes * 60))
+}
+
+/// The time before which no further answers may be submitted, persisted between runs so
+/// that waiting periods imposed by the site are respected.
+struct Throttle {
+    path: PathBuf,
+}
+
+impl Throttle {
+    fn new(dir: &Path) -> Self {
+        Throttle {
+            path: dir.join("throttle"),
+        }
+    }
+
+    /// Return how long remains until the next submission is allowed.
+    fn remaining(&self) -> Duration {
+        let Some(until) = fs::read_to_string(&self.path)
+            .ok()
+            .and_then(|contents| contents.trim().parse().ok())
+        else {
+            return Duration::ZERO;
+        };
+        (UNIX_EPOCH + Duration::from_secs(until))
+            .duration_since(SystemTime::now())
+            .unwrap_or(Duration::ZERO)
+    }
+
+    /// Disallow submissions for the given period from now.
+    fn wait_for(&self, wait: Duration) -> Result<(), String> {
+        let until = (SystemTime::now() + wait)
+            .duration_since(UNIX_EPOCH)
+            .expect("Current time should be after the epoch");
+        // Round up so that the stored time is never earlier than requested
+        let until = until.as_secs() + u64::from(until.subsec_nanos() > 0);
+        fs::create_dir_all(self.path.parent().unwrap())
+            .and_then(|_| fs::write(&self.path, until.to_string()))
+            .map_err(|err| format!("Cannot write {}: {err}", self.path.display()))
+    }
+}
+
+/// Submit the answer for the given day and part, respecting any waiting period imposed by
+/// the site. When `wait` is set, sleep until submissions are allowed again, including after
+/// being rate limited; otherwise fail if a waiting period is known to be in effect.
+///
+/// Every checked answer is recorded in the day's journal, and answers that earlier attempts
+/// show to be wrong are refused without being submitted.
+pub fn submit(
+    client: &AocClient,
+    year: u32,
+    day: u32,
+    part: u32,
+    answer: &Answer,
+    submissions_dir: &Path,
+    wait: bool,
+) -> Result<SubmitOutcome, String> {
+    if let Answer::Grid(_) = answer {
+        return Err("A grid answer must be read and submitted by hand".to_string());
+    }
+    let answer = answer.to_string();
+    let mut journal = Journal::load(submissions_dir, day)?;
+    journal.check(part, &answer)?;
+    let throttle = Throttle::new(submissions_dir);
+
+    loop {
+        let remaining = throttle.remaining();
+        if !remaining.is_zero() {
+            if !wait {
+                return Err(format!(
+                    "Submissions are not allowed for another {}",
+                    format_duration(remaining)
+                ));
+            }
+            println!(
+                "Waiting {} before submitting...",
+                format_duration(remaining)
+            );
+            thread::sleep(remaining);
+        }
+
+        let outcome = parse_response(&client.post_answer(year, day, part, &answer)?);
+        match &outcome {
+            SubmitOutcome::RateLimited { wait: remaining } => {
+                throttle.wait_for(*remaining)?;
+                if wait {
+                    continue;
+                }
+            }
+            SubmitOutcome::Wrong {
+                wait: Some(penalty),
+                ..
+            } => throttle.wait_for(*penalty)?,
+            _ => {}
+        }
+        if let Some(attempt) = Attempt::from_outcome(&answer, &outcome) {
+            journal.record(part, attempt);
+            journal.save()?;
+        }
+        return Ok(outcome);
+    }
+}
+
+/// Solve the given day and part on its default input and submit the answer to the site at
+/// the given base URL, as described for [`submit`].
+pub fn solve_and_submit(
+    registry: &Registry,
+    day: u32,
+    part: u32,
+    base_url: &str,
+    wait: bool,
+) -> Result<SubmitOutcome, String> {
+    let solver = registry
+        .get(day)
+        .ok_or_else(|| format!("No solver registered for day {day}"))?;
+    let client = AocClient::from_env(Some(base_url))?;
+    let answer = solver.run(part, &registry.default_input())?;
+    println!("Submitting answer for day {day}, part {part}: {answer}");
+    submit(
+        &client,
+        registry.year(),
+        day,
+        part,
+        &answer,
+        &registry.submissions_dir(),
+        wait,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{client::mock::MockServer, test_utils::TempDir};
+
+    fn page(text: &str) -> String {
+        format!("<html><body><main>\n<article><p>{text}</p></article>\n</main></body></html>")
+    }
+
+    #[test]
+    fn parses_verdicts() {
+        assert_eq!(
+            parse_response(&page(
+                "That's the right answer! You are <span class=\"day-success\">one gold star</span> closer."
+            )),
+            SubmitOutcome::Correct
+        );
+        assert_eq!(
+            parse_response(&page(
+                "That's not the right answer; your answer is too high. Please wait one minute before trying again."
+            )),
+            SubmitOutcome::Wrong {
+                hint: Some(Hint::TooHigh),
+                wait: Some(Duration::from_secs(60))
+            }
+        );
+        assert_eq!(
+            parse_response(&page(
+                "That's not the right answer; your answer is too low. Please wait 5 minutes before trying again."
+            )),
+            SubmitOutcome::Wrong {
+                hint: Some(Hint::TooLow),
+                wait: Some(Duration::from_secs(300))
+            }
+        );
+        assert_eq!(
+            parse_response(&page(
+                "You gave an answer too recently; you have to wait after submitting an answer before trying again. You have 1m 12s left to wait."
+            )),
+            SubmitOutcome::RateLimited {
+                wait: Duration::from_secs(72)
+            }
+        );
+        assert_eq!(
+            parse_response(&page(
+                "You don't seem to be solving the right level. Did you already complete it?"
+            )),
+            SubmitOutcome::WrongLevel
+        );
+        assert_eq!(
+            parse_response(&page("Something   <em>else</em>")),
+            SubmitOutcome::Unrecognized("Something else".to_string())
+        );
+    }
+
+    #[test]
+    fn retries_after_rate_limit() {
+        let server = MockServer::start(vec![
+            (
+                200,
+                page("You gave an answer too recently. You have 1s left to wait."),
+            ),
+            (200, page("That's the right answer!")),
+        ]);
+        let client = AocClient::new(&server.base_url, "abc123");
+        let dir = TempDir::new("submit-retry");
+
+        let outcome = submit(&client, 2024, 7, 2, &Answer::Int(42), &dir, true);
+        assert_eq!(outcome, Ok(SubmitOutcome::Correct));
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[1].method, "POST");
+        assert_eq!(requests[1].path, "/2024/day/7/answer");
+        assert_eq!(requests[1].body, "level=2&answer=42");
+    }
+
+    #[test]
+    fn refuses_to_submit_during_penalty() {
+        let server = MockServer::start(vec![(
+            200,
+            page("That's not the right answer. Please wait one minute before trying again."),
+        )]);
+        let client = AocClient::new(&server.base_url, "abc123");
+        let dir = TempDir::new("submit-penalty");
+
+        let outcome = submit(&client, 2024, 1, 1, &Answer::Int(1), &dir, false);
+        assert!(matches!(
+            outcome,
+            Ok(SubmitOutcome::Wrong { hint: None, .. })
+        ));
+
+        // Later attempts fail without contacting the site, whether the answer is known to
+        // be wrong or the penalty for the wrong answer is still in effect
+        let err = submit(&client, 2024, 1, 1, &Answer::Int(1), &dir, false).unwrap_err();
+        assert!(err.contains("ruled out by attempt 1"), "{err}");
+        let err = submit(&client, 2024, 1, 1, &Answer::Int(2), &dir, false).unwrap_err();
+        assert!(err.contains("not allowed"), "{err}");
+        assert_eq!(server.requests().len(), 1);
+    }
+}
@@ -0,0 +1,34 @@
+use std::{
+    env, fs,
+    ops::Deref,
+    path::{Path, PathBuf},
+    process,
+};
+
+/// A scratch directory for a single test, which is removed along with its contents when
+/// dropped, including when the test fails.
+pub struct TempDir(PathBuf);
+
+impl TempDir {
+    /// Create an empty directory named after the given test, unique to this process.
+    pub fn new(name: &str) -> Self {
+        let path = env::temp_dir().join(format!("aoc-{name}-test-{}", process::id()));
+        let _ = fs::remove_dir_all(&path);
+        fs::create_dir_all(&path).unwrap();
+        TempDir(path)
+    }
+}
+
+impl Deref for TempDir {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
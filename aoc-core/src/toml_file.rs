@@ -0,0 +1,24 @@
+use std::{fs, io::ErrorKind, path::Path};
+use toml::Table;
+
+/// Load the table stored in the TOML file at the given path, naming the file by what it
+/// holds in errors. A missing file is treated as an empty table.
+pub fn load(path: &Path, description: &str) -> Result<Table, String> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == ErrorKind::NotFound => return Ok(Table::new()),
+        Err(err) => return Err(format!("Cannot read {}: {err}", path.display())),
+    };
+    contents
+        .parse()
+        .map_err(|err| format!("Invalid {description} {}: {err}", path.display()))
+}
+
+/// Write the table to the TOML file at the given path, creating its directory if needed and
+/// replacing any existing file.
+pub fn save(path: &Path, table: &Table) -> Result<(), String> {
+    path.parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| fs::write(path, table.to_string()))
+        .map_err(|err| format!("Cannot write {}: {err}", path.display()))
+}
@@ -0,0 +1,106 @@
+mod geometry;
+mod grid;
+mod sparse_grid;
+
+pub use geometry::{Direction, Point, Vector};
+pub use grid::Grid;
+pub use sparse_grid::SparseGrid;
+
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader},
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+/// Where a solver reads its puzzle input from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// The day's input file in the given inputs directory
+    Default(PathBuf),
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Interpret a command-line input argument, where "-" denotes stdin and no argument
+    /// denotes the day's input file in the given inputs directory.
+    pub fn from_arg(arg: Option<PathBuf>, inputs_dir: &Path) -> Self {
+        match arg {
+            None => InputSource::Default(inputs_dir.to_path_buf()),
+            Some(path) if path.as_os_str() == "-" => InputSource::Stdin,
+            Some(path) => InputSource::File(path),
+        }
+    }
+
+    /// Return the path that will be read for the given day, if the source is a file.
+    pub fn path(&self, day: u32) -> Option<PathBuf> {
+        match self {
+            InputSource::Default(dir) => Some(default_input_path(dir, day)),
+            InputSource::File(path) => Some(path.clone()),
+            InputSource::Stdin => None,
+        }
+    }
+
+    /// Return a human-readable name for the source of the given day's input.
+    pub fn describe(&self, day: u32) -> String {
+        match self.path(day) {
+            Some(path) => path.display().to_string(),
+            None => "stdin".to_string(),
+        }
+    }
+
+    /// Read all lines of input for the given day.
+    pub fn read_lines(&self, day: u32) -> io::Result<Vec<String>> {
+        match self.path(day) {
+            Some(path) => lines_from_file(path),
+            None => {
+                // Stdin can only be consumed once, so keep its contents for any later reads,
+                // holding the lock while reading so that concurrent readers wait for them
+                static STDIN_LINES: Mutex<Option<Vec<String>>> = Mutex::new(None);
+                let mut cached = STDIN_LINES.lock().unwrap();
+                if cached.is_none() {
+                    *cached = Some(io::stdin().lock().lines().collect::<io::Result<_>>()?);
+                }
+                Ok(cached.clone().unwrap_or_default())
+            }
+        }
+    }
+}
+
+/// Return the conventional location of the input file for the given day within the
+/// inputs directory.
+pub fn default_input_path(inputs_dir: &Path, day: u32) -> PathBuf {
+    inputs_dir.join(format!("day{day:02}.txt"))
+}
+
+pub fn lines_from_file(filename: impl AsRef<Path>) -> io::Result<Vec<String>> {
+    BufReader::new(File::open(filename)?).lines().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_input_sources() {
+        let inputs_dir = Path::new("inputs");
+        let default = InputSource::from_arg(None, inputs_dir);
+        assert_eq!(default, InputSource::Default(inputs_dir.to_path_buf()));
+        assert_eq!(default.path(7), Some(inputs_dir.join("day07.txt")));
+        assert_eq!(
+            default.describe(7),
+            inputs_dir.join("day07.txt").display().to_string()
+        );
+
+        let stdin = InputSource::from_arg(Some(PathBuf::from("-")), inputs_dir);
+        assert_eq!(stdin, InputSource::Stdin);
+        assert_eq!(stdin.path(7), None);
+        assert_eq!(stdin.describe(7), "stdin");
+
+        let file = InputSource::from_arg(Some(PathBuf::from("other.txt")), inputs_dir);
+        assert_eq!(file, InputSource::File(PathBuf::from("other.txt")));
+        assert_eq!(file.path(7), Some(PathBuf::from("other.txt")));
+        assert_eq!(file.describe(7), "other.txt");
+    }
+}
@@ -0,0 +1,221 @@
+use std::{
+    fmt,
+    ops::{Add, AddAssign, Mul, Neg, Sub},
+};
+
+/// A position given by its row and column, with rows increasing downwards. Coordinates are
+/// signed so that positions just outside a grid can still be represented.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub row: isize,
+    pub col: isize,
+}
+
+impl Point {
+    pub const fn new(row: isize, col: isize) -> Self {
+        Point { row, col }
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.row, self.col)
+    }
+}
+
+/// A displacement between two points, in rows and columns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Vector {
+    pub row: isize,
+    pub col: isize,
+}
+
+impl Vector {
+    pub const fn new(row: isize, col: isize) -> Self {
+        Vector { row, col }
+    }
+
+    /// Return the vector rotated a quarter turn clockwise.
+    pub const fn turn_right(self) -> Self {
+        Vector::new(self.col, -self.row)
+    }
+
+    /// Return the vector rotated a quarter turn anticlockwise.
+    pub const fn turn_left(self) -> Self {
+        Vector::new(-self.col, self.row)
+    }
+}
+
+impl Add<Vector> for Point {
+    type Output = Point;
+
+    fn add(self, vector: Vector) -> Point {
+        Point::new(self.row + vector.row, self.col + vector.col)
+    }
+}
+
+impl AddAssign<Vector> for Point {
+    fn add_assign(&mut self, vector: Vector) {
+        *self = *self + vector;
+    }
+}
+
+impl Sub<Vector> for Point {
+    type Output = Point;
+
+    fn sub(self, vector: Vector) -> Point {
+        self + -vector
+    }
+}
+
+impl Sub for Point {
+    type Output = Vector;
+
+    fn sub(self, other: Point) -> Vector {
+        Vector::new(self.row - other.row, self.col - other.col)
+    }
+}
+
+impl Add for Vector {
+    type Output = Vector;
+
+    fn add(self, other: Vector) -> Vector {
+        Vector::new(self.row + other.row, self.col + other.col)
+    }
+}
+
+impl Neg for Vector {
+    type Output = Vector;
+
+    fn neg(self) -> Vector {
+        Vector::new(-self.row, -self.col)
+    }
+}
+
+impl Mul<isize> for Vector {
+    type Output = Vector;
+
+    fn mul(self, factor: isize) -> Vector {
+        Vector::new(self.row * factor, self.col * factor)
+    }
+}
+
+/// One of the eight compass directions, with north pointing up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Direction {
+    /// The four orthogonal directions, clockwise from north
+    pub const ORTHOGONAL: [Direction; 4] = [Direction::N, Direction::E, Direction::S, Direction::W];
+
+    /// All eight directions, clockwise from north
+    pub const ALL: [Direction; 8] = [
+        Direction::N,
+        Direction::NE,
+        Direction::E,
+        Direction::SE,
+        Direction::S,
+        Direction::SW,
+        Direction::W,
+        Direction::NW,
+    ];
+
+    /// Return the direction the given number of eighth turns clockwise from this one.
+    fn rotate(self, eighths: usize) -> Self {
+        Direction::ALL[(self as usize + eighths) % 8]
+    }
+
+    /// Return the direction a quarter turn clockwise from this one.
+    pub fn turn_right(self) -> Self {
+        self.rotate(2)
+    }
+
+    /// Return the direction a quarter turn anticlockwise from this one.
+    pub fn turn_left(self) -> Self {
+        self.rotate(6)
+    }
+
+    /// Return the opposite direction.
+    pub fn reverse(self) -> Self {
+        self.rotate(4)
+    }
+
+    /// Return the unit step in this direction, with diagonal steps moving one row and
+    /// one column at once.
+    pub const fn vector(self) -> Vector {
+        match self {
+            Direction::N => Vector::new(-1, 0),
+            Direction::NE => Vector::new(-1, 1),
+            Direction::E => Vector::new(0, 1),
+            Direction::SE => Vector::new(1, 1),
+            Direction::S => Vector::new(1, 0),
+            Direction::SW => Vector::new(1, -1),
+            Direction::W => Vector::new(0, -1),
+            Direction::NW => Vector::new(-1, -1),
+        }
+    }
+}
+
+impl From<Direction> for Vector {
+    fn from(direction: Direction) -> Self {
+        direction.vector()
+    }
+}
+
+/// Parse an orthogonal direction from an arrow (`^>v<`), a compass point (`NESW`) or
+/// a relative move (`UDLR`).
+impl TryFrom<char> for Direction {
+    type Error = String;
+
+    fn try_from(c: char) -> Result<Self, String> {
+        match c {
+            '^' | 'N' | 'U' => Ok(Direction::N),
+            '>' | 'E' | 'R' => Ok(Direction::E),
+            'v' | 'S' | 'D' => Ok(Direction::S),
+            '<' | 'W' | 'L' => Ok(Direction::W),
+            _ => Err(format!("Expected a direction, found '{c}'")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turns_and_steps() {
+        assert_eq!(Direction::N.turn_right(), Direction::E);
+        assert_eq!(Direction::NW.turn_right(), Direction::NE);
+        assert_eq!(Direction::N.turn_left(), Direction::W);
+        assert_eq!(Direction::SE.reverse(), Direction::NW);
+        for direction in Direction::ALL {
+            assert_eq!(
+                direction.turn_right().vector(),
+                direction.vector().turn_right()
+            );
+            assert_eq!(direction.turn_left().turn_right(), direction);
+        }
+
+        let (a, b) = (Point::new(1, 8), Point::new(2, 5));
+        assert_eq!(a - b, Vector::new(-1, 3));
+        assert_eq!(a + (a - b) * 2, Point::new(-1, 14));
+        assert_eq!(
+            "^>v<NESWURDL"
+                .chars()
+                .map(Direction::try_from)
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap(),
+            [Direction::ORTHOGONAL; 3].concat()
+        );
+        assert!(Direction::try_from('x').is_err());
+    }
+}
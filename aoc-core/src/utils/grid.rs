@@ -0,0 +1,326 @@
+use super::{Direction, Point, Vector};
+use crate::parse::{check_rectangular, ParseError};
+use std::{
+    fmt,
+    iter::successors,
+    ops::{Index, IndexMut},
+};
+
+/// A rectangular grid of cells stored row by row in a single buffer, indexed by points
+/// with the origin at the top left.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Create a grid of the given size with every cell set to the given value.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Parse a grid with one row per line, converting each character into a cell with the
+    /// given function. Fails at the first character that cannot be converted, pointing at it
+    /// with the message returned by the function, or if the lines are empty or ragged.
+    pub fn parse(
+        lines: &[String],
+        mut f: impl FnMut(char) -> Result<T, String>,
+    ) -> Result<Self, ParseError> {
+        let rows: Vec<Vec<T>> = lines
+            .iter()
+            .enumerate()
+            .map(|(i, line)| {
+                line.char_indices()
+                    .map(|(offset, c)| {
+                        f(c).map_err(|message| {
+                            ParseError::at(i, line, &line[offset..offset + c.len_utf8()], message)
+                        })
+                    })
+                    .collect()
+            })
+            .collect::<Result<_, _>>()?;
+        check_rectangular(lines, &rows)?;
+
+        Ok(Grid {
+            width: rows[0].len(),
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Return the index into the cell buffer of the given point, if it lies within the grid.
+    fn offset(&self, point: Point) -> Option<usize> {
+        let row = usize::try_from(point.row).ok()?;
+        let col = usize::try_from(point.col).ok()?;
+        (row < self.height && col < self.width).then_some(row * self.width + col)
+    }
+
+    /// Return the point of the cell at the given index into the cell buffer.
+    fn point_at(&self, offset: usize) -> Point {
+        Point::new(
+            (offset / self.width) as isize,
+            (offset % self.width) as isize,
+        )
+    }
+
+    /// Return whether the given point lies within the grid.
+    pub fn contains(&self, point: Point) -> bool {
+        self.offset(point).is_some()
+    }
+
+    /// Return the cell at the given point, or None if it lies outside the grid.
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.offset(point).map(|i| &self.cells[i])
+    }
+
+    /// Return the cell at the given point mutably, or None if it lies outside the grid.
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.offset(point).map(|i| &mut self.cells[i])
+    }
+
+    /// Return the hypothetical result of taking the given step, which may be a direction,
+    /// from the provided starting point. Return None if the step would be out of bounds.
+    pub fn try_step(&self, start: Point, step: impl Into<Vector>) -> Option<Point> {
+        let end = start + step.into();
+        self.contains(end).then_some(end)
+    }
+
+    /// Return the point within the grid that the given point corresponds to when the grid
+    /// wraps around at its edges, as on a torus. Panics if the grid is empty.
+    pub fn wrap(&self, point: Point) -> Point {
+        Point::new(
+            point.row.rem_euclid(self.height as isize),
+            point.col.rem_euclid(self.width as isize),
+        )
+    }
+
+    /// Return the result of taking the given step from the provided starting point, wrapping
+    /// around to the opposite edge when leaving the grid. Panics if the grid is empty.
+    pub fn wrapping_step(&self, start: Point, step: impl Into<Vector>) -> Point {
+        self.wrapping_steps(start, step, 1)
+    }
+
+    /// Return the result of taking the given step the given number of times, which may be
+    /// negative to move backwards, wrapping around at the edges of the grid. The result is
+    /// computed directly, however many steps are taken. Panics if the grid is empty.
+    pub fn wrapping_steps(&self, start: Point, step: impl Into<Vector>, times: isize) -> Point {
+        let step = step.into();
+        // Reduce each factor first so that the product cannot overflow
+        let wrap_axis = |start: isize, step: isize, len: usize| {
+            let len = len as i128;
+            let moved = (step as i128).rem_euclid(len) * (times as i128).rem_euclid(len);
+            (start as i128 + moved).rem_euclid(len) as isize
+        };
+        Point::new(
+            wrap_axis(start.row, step.row, self.height),
+            wrap_axis(start.col, step.col, self.width),
+        )
+    }
+
+    /// Iterate over the orthogonal neighbours of the given point that lie within the grid,
+    /// clockwise from north.
+    pub fn neighbours(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::ORTHOGONAL
+            .into_iter()
+            .filter_map(move |direction| self.try_step(point, direction))
+    }
+
+    /// Iterate over the neighbours of the given point in all eight directions, including
+    /// diagonals, that lie within the grid, clockwise from north.
+    pub fn all_neighbours(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.try_step(point, direction))
+    }
+
+    /// Iterate over the points reached by repeatedly taking the given step from the start,
+    /// which is itself excluded, until the edge of the grid.
+    pub fn ray(&self, start: Point, step: impl Into<Vector>) -> impl Iterator<Item = Point> + '_ {
+        let step = step.into();
+        successors(Some(start), move |&point| self.try_step(point, step)).skip(1)
+    }
+
+    /// Iterate over up to the given number of points starting at the given point and taking
+    /// the given step between each, stopping early at the edge of the grid. Nothing is
+    /// produced if the start lies outside the grid.
+    pub fn segment(
+        &self,
+        start: Point,
+        step: impl Into<Vector>,
+        len: usize,
+    ) -> impl Iterator<Item = Point> + '_ {
+        let step = step.into();
+        let start = self.contains(start).then_some(start);
+        successors(start, move |&point| self.try_step(point, step)).take(len)
+    }
+
+    /// Return the point of the first cell, in row order, satisfying the predicate.
+    pub fn find(&self, predicate: impl Fn(&T) -> bool) -> Option<Point> {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|i| self.point_at(i))
+    }
+
+    /// Iterate over the rows from top to bottom. A grid of zero width has as many rows as its
+    /// height, each of them empty.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        (0..self.height).map(|row| &self.cells[row * self.width..(row + 1) * self.width])
+    }
+
+    /// Iterate over the cells of the given column from top to bottom.
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> + '_ {
+        assert!(col < self.width, "Column {col} is outside the grid");
+        self.cells.iter().skip(col).step_by(self.width)
+    }
+
+    /// Iterate over the columns from left to right.
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T> + '_> + '_ {
+        (0..self.width).map(|col| self.column(col))
+    }
+
+    /// Iterate over the points of every cell in row order.
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        (0..self.cells.len()).map(|i| self.point_at(i))
+    }
+
+    /// Iterate over every cell along with its point in row order.
+    pub fn cells(&self) -> impl Iterator<Item = (Point, &T)> + '_ {
+        self.points().zip(&self.cells)
+    }
+
+    /// Return a grid of the same size with the function applied to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("Point {point} is outside the grid"))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("Point {point} is outside the grid"))
+    }
+}
+
+/// Render each row on its own line, with the cells of a row written one after another.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::Direction;
+
+    fn lines(text: &str) -> Vec<String> {
+        text.lines().map(String::from).collect()
+    }
+
+    #[test]
+    fn parses_and_indexes_cells() {
+        let grid = Grid::parse(&lines("123\n456"), |c| {
+            c.to_digit(10)
+                .ok_or_else(|| format!("Expected a digit, found '{c}'"))
+        })
+        .unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point::new(1, 0)], 4);
+        assert_eq!(grid.get(Point::new(2, 0)), None);
+        assert_eq!(grid.get(Point::new(0, -1)), None);
+        assert_eq!(grid.find(|&n| n > 4), Some(Point::new(1, 1)));
+        assert_eq!(
+            grid.try_step(Point::new(0, 2), Direction::SW),
+            Some(Point::new(1, 1))
+        );
+        assert_eq!(grid.try_step(Point::new(0, 2), Vector::new(0, 1)), None);
+        assert_eq!(
+            grid.neighbours(Point::new(0, 1)).collect::<Vec<_>>(),
+            [Point::new(0, 2), Point::new(1, 1), Point::new(0, 0)]
+        );
+        assert_eq!(grid.all_neighbours(Point::new(1, 0)).count(), 3);
+        assert_eq!(
+            grid.ray(Point::new(0, 0), Direction::E)
+                .map(|point| grid[point])
+                .collect::<Vec<_>>(),
+            vec![2, 3]
+        );
+        assert_eq!(grid.segment(Point::new(0, 0), Direction::SE, 3).count(), 2);
+        assert_eq!(grid.segment(Point::new(-1, 0), Direction::S, 3).count(), 0);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+        assert_eq!(grid.cells().last(), Some((Point::new(1, 2), &6)));
+        assert_eq!(grid.map(|n| n * 2).to_string(), "246\n81012");
+
+        let empty = Grid::new(0, 2, 0);
+        assert_eq!(empty.rows().collect::<Vec<_>>(), [[], []]);
+        assert_eq!(empty.to_string(), "\n");
+
+        let err = Grid::parse(&lines("12\n3x"), |c| {
+            c.to_digit(10)
+                .ok_or_else(|| format!("Expected a digit, found '{c}'"))
+        })
+        .unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+        let err = Grid::parse(&lines("ab\nc"), Ok).unwrap_err();
+        assert_eq!(err.message, "Expected a row of width 2, found width 1");
+    }
+
+    #[test]
+    fn wraps_around_edges() {
+        // A robot moving across an 11 by 7 area, wrapping at the edges
+        let area = Grid::new(11, 7, ());
+        let (start, velocity) = (Point::new(4, 2), Vector::new(-3, 2));
+        let mut pos = start;
+        for _ in 0..5 {
+            pos = area.wrapping_step(pos, velocity);
+        }
+        assert_eq!(pos, Point::new(3, 1));
+        assert_eq!(area.wrapping_steps(start, velocity, 5), pos);
+        assert_eq!(area.wrapping_steps(pos, velocity, -5), start);
+        assert_eq!(area.wrapping_steps(start, velocity, 77), start);
+        assert_eq!(
+            area.wrapping_steps(start, Vector::new(isize::MAX, isize::MIN), isize::MAX),
+            Point::new(4, 1)
+        );
+        assert_eq!(area.wrap(Point::new(-1, 11)), Point::new(6, 0));
+    }
+}
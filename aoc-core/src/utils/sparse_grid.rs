@@ -0,0 +1,170 @@
+use super::{Direction, Point};
+use std::{
+    collections::HashMap,
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+/// An unbounded grid holding only the cells that have been set, keyed by signed points.
+/// Every other cell reads as the default value. The bounding box of the set cells is kept
+/// up to date as cells are added and removed.
+#[derive(Debug, Clone)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point, T>,
+    default: T,
+    /// The top left and bottom right corners of the set cells, if there are any
+    bounds: Option<(Point, Point)>,
+}
+
+impl<T> SparseGrid<T> {
+    /// Create an empty grid in which every cell reads as the given value.
+    pub fn new(default: T) -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+            default,
+            bounds: None,
+        }
+    }
+
+    /// Return the number of cells that have been set.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Return whether the cell at the given point has been set.
+    pub fn contains(&self, point: Point) -> bool {
+        self.cells.contains_key(&point)
+    }
+
+    /// Return the cell at the given point, which is the default value if it has not been set.
+    pub fn get(&self, point: Point) -> &T {
+        self.cells.get(&point).unwrap_or(&self.default)
+    }
+
+    /// Set the cell at the given point, returning its previous value if it had been set.
+    pub fn insert(&mut self, point: Point, value: T) -> Option<T> {
+        self.extend_bounds(point);
+        self.cells.insert(point, value)
+    }
+
+    /// Unset the cell at the given point, returning its value if it had been set.
+    pub fn remove(&mut self, point: Point) -> Option<T> {
+        let value = self.cells.remove(&point)?;
+        // The bounding box can only shrink if the cell was on its edge
+        if let Some((min, max)) = self.bounds {
+            if [min.row, max.row].contains(&point.row) || [min.col, max.col].contains(&point.col) {
+                self.bounds = None;
+                let points: Vec<Point> = self.cells.keys().copied().collect();
+                for point in points {
+                    self.extend_bounds(point);
+                }
+            }
+        }
+        Some(value)
+    }
+
+    fn extend_bounds(&mut self, point: Point) {
+        self.bounds = Some(match self.bounds {
+            None => (point, point),
+            Some((min, max)) => (
+                Point::new(min.row.min(point.row), min.col.min(point.col)),
+                Point::new(max.row.max(point.row), max.col.max(point.col)),
+            ),
+        });
+    }
+
+    /// Return the top left and bottom right corners of the smallest rectangle containing every
+    /// cell that has been set, or None if no cells have been set.
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        self.bounds
+    }
+
+    /// Iterate over the cells that have been set along with their points, in no particular
+    /// order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> + '_ {
+        self.cells.iter().map(|(&point, value)| (point, value))
+    }
+
+    /// Iterate over the orthogonal neighbours of the given point, clockwise from north.
+    pub fn neighbours(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::ORTHOGONAL
+            .into_iter()
+            .map(move |direction| point + direction.vector())
+    }
+
+    /// Iterate over the neighbours of the given point in all eight directions, including
+    /// diagonals, clockwise from north.
+    pub fn all_neighbours(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::ALL
+            .into_iter()
+            .map(move |direction| point + direction.vector())
+    }
+}
+
+impl<T> Index<Point> for SparseGrid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point)
+    }
+}
+
+/// Mutable access sets the cell to the default value first if it has not been set.
+impl<T: Clone> IndexMut<Point> for SparseGrid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        self.extend_bounds(point);
+        self.cells
+            .entry(point)
+            .or_insert_with(|| self.default.clone())
+    }
+}
+
+/// Render the cells within the bounding box, one row per line, for inspecting the grid
+/// while debugging. Cells that have not been set are rendered as the default value.
+impl<T: fmt::Display> fmt::Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some((min, max)) = self.bounds else {
+            return Ok(());
+        };
+        for row in min.row..=max.row {
+            if row > min.row {
+                writeln!(f)?;
+            }
+            for col in min.col..=max.col {
+                write!(f, "{}", self.get(Point::new(row, col)))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tracks_bounds_of_set_cells() {
+        let mut grid = SparseGrid::new('.');
+        assert_eq!(grid.to_string(), "");
+        grid.insert(Point::new(-1, 2), '#');
+        grid.insert(Point::new(1, -1), '#');
+        grid[Point::new(0, 0)] = '@';
+        assert_eq!(grid[Point::new(5, 5)], '.');
+        assert_eq!(grid.bounds(), Some((Point::new(-1, -1), Point::new(1, 2))));
+        assert_eq!(grid.to_string(), "...#\n.@..\n#...");
+
+        assert_eq!(grid.remove(Point::new(-1, 2)), Some('#'));
+        assert_eq!(grid.len(), 2);
+        assert_eq!(grid.to_string(), ".@\n#.");
+        assert_eq!(
+            grid.all_neighbours(Point::new(0, 0))
+                .filter(|&point| grid.contains(point))
+                .collect::<Vec<_>>(),
+            vec![Point::new(1, -1)]
+        );
+    }
+}
@@ -0,0 +1,266 @@
+use crate::{
+    answer::Answer,
+    runner::{self, RunError, RunResult},
+    solver::Registry,
+    toml_file,
+};
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+use toml::{Table, Value};
+
+/// The outcome of checking a single day and part against its accepted answer.
+pub enum Verdict {
+    Correct,
+    Mismatch {
+        expected: Answer,
+        actual: Answer,
+    },
+    /// No accepted answer has been recorded yet
+    Unverified(Answer),
+    Failed(RunError),
+}
+
+pub struct VerifyResult {
+    pub day: u32,
+    pub part: u32,
+    pub verdict: Verdict,
+}
+
+/// Return the location of the accepted answers file for the given day.
+pub fn answers_path(dir: &Path, day: u32) -> PathBuf {
+    dir.join(format!("day{day:02}.toml"))
+}
+
+/// Load the accepted answers for the given day, keyed by part. A missing answers file
+/// is treated as having no accepted answers.
+pub fn load_answers(dir: &Path, day: u32) -> Result<BTreeMap<u32, Answer>, String> {
+    let path = answers_path(dir, day);
+    let table = toml_file::load(&path, "answers file")?;
+
+    let mut answers = BTreeMap::new();
+    for part in [1, 2] {
+        if let Some(value) = table.get(&part_key(part)) {
+            let answer = answer_from_toml(value).ok_or_else(|| {
+                format!(
+                    "Unsupported answer for part {part} in {}: {value}",
+                    path.display()
+                )
+            })?;
+            answers.insert(part, answer);
+        }
+    }
+    Ok(answers)
+}
+
+/// Write the given accepted answers for a day, replacing any existing answers file.
+pub fn save_answers(dir: &Path, day: u32, answers: &BTreeMap<u32, Answer>) -> Result<(), String> {
+    let path = answers_path(dir, day);
+    let table: Table = answers
+        .iter()
+        .map(|(&part, answer)| (part_key(part), answer_to_toml(answer)))
+        .collect();
+    toml_file::save(&path, &table)
+}
+
+fn part_key(part: u32) -> String {
+    format!("part{part}")
+}
+
+fn answer_from_toml(value: &Value) -> Option<Answer> {
+    match value {
+        Value::Integer(n) => Some(Answer::Int(*n)),
+        Value::String(s) => Some(Answer::Text(s.clone())),
+        Value::Array(rows) => rows
+            .iter()
+            .map(|row| row.as_str().map(String::from))
+            .collect::<Option<Vec<String>>>()
+            .map(Answer::Grid),
+        _ => None,
+    }
+}
+
+fn answer_to_toml(answer: &Answer) -> Value {
+    match answer {
+        Answer::Int(n) => Value::Integer(*n),
+        Answer::Text(s) => Value::String(s.clone()),
+        Answer::Grid(rows) => Value::Array(rows.iter().cloned().map(Value::String).collect()),
+    }
+}
+
+/// Rerun the selected solvers on their default inputs, using the given number of worker
+/// threads and time limit per solver, and compare each answer against the accepted answer
+/// stored in the given directory.
+pub fn run_verify(
+    registry: &Registry,
+    days: impl Iterator<Item = u32>,
+    parts: &[u32],
+    answers_dir: &Path,
+    jobs: usize,
+    timeout: Option<Duration>,
+) -> Vec<VerifyResult> {
+    let source = registry.default_input();
+    let results = runner::run_batch(registry, days, parts, &source, jobs, timeout);
+
+    let mut accepted: BTreeMap<u32, Result<BTreeMap<u32, Answer>, String>> = BTreeMap::new();
+    let mut verify_results = Vec::new();
+    for RunResult {
+        day, part, outcome, ..
+    } in results
+    {
+        let expected = accepted
+            .entry(day)
+            .or_insert_with(|| load_answers(answers_dir, day));
+        let verdict = match (outcome, expected) {
+            (Err(err), _) => Verdict::Failed(err),
+            (_, Err(err)) => Verdict::Failed(RunError::Failed(err.clone())),
+            (Ok(actual), Ok(expected)) => match expected.get(&part) {
+                None => Verdict::Unverified(actual),
+                Some(expected) if *expected == actual => Verdict::Correct,
+                Some(expected) => Verdict::Mismatch {
+                    expected: expected.clone(),
+                    actual,
+                },
+            },
+        };
+        verify_results.push(VerifyResult { day, part, verdict });
+    }
+    verify_results
+}
+
+/// Record the answers of all unverified results as accepted, leaving existing accepted
+/// answers untouched. Return the number of answers recorded.
+pub fn record_unverified(results: &[VerifyResult], answers_dir: &Path) -> Result<usize, String> {
+    let mut new_answers: BTreeMap<u32, Vec<(u32, Answer)>> = BTreeMap::new();
+    for result in results {
+        if let Verdict::Unverified(answer) = &result.verdict {
+            new_answers
+                .entry(result.day)
+                .or_default()
+                .push((result.part, answer.clone()));
+        }
+    }
+
+    let mut num_recorded = 0;
+    for (day, answers) in new_answers {
+        let mut accepted = load_answers(answers_dir, day)?;
+        num_recorded += answers.len();
+        accepted.extend(answers);
+        save_answers(answers_dir, day, &accepted)?;
+    }
+    Ok(num_recorded)
+}
+
+/// Print a table of the provided verification results, showing the expected and actual
+/// answers of any that do not match.
+pub fn print_verify(results: &[VerifyResult]) {
+    println!("{:>3}  {:>4}  {:<10}  Details", "Day", "Part", "Status");
+    for result in results {
+        let (status, details) = match &result.verdict {
+            Verdict::Correct => ("OK", String::new()),
+            Verdict::Mismatch { expected, actual } => (
+                "MISMATCH",
+                format!(
+                    "expected {}, got {}",
+                    expected.one_line(),
+                    actual.one_line()
+                ),
+            ),
+            Verdict::Unverified(actual) => ("UNVERIFIED", format!("got {}", actual.one_line())),
+            Verdict::Failed(err) => (err.status(), err.to_string()),
+        };
+        let row = format!(
+            "{:>3}  {:>4}  {:<10}  {details}",
+            result.day, result.part, status
+        );
+        println!("{}", row.trim_end());
+    }
+
+    let count = |f: fn(&Verdict) -> bool| results.iter().filter(|r| f(&r.verdict)).count();
+    println!(
+        "\n{} correct, {} mismatched, {} unverified, {} failed",
+        count(|v| matches!(v, Verdict::Correct)),
+        count(|v| matches!(v, Verdict::Mismatch { .. })),
+        count(|v| matches!(v, Verdict::Unverified(_))),
+        count(|v| matches!(v, Verdict::Failed(_))),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse::ParseError, solver::Solver, test_utils::TempDir};
+    use std::fs;
+
+    struct Counter;
+
+    impl Solver for Counter {
+        const DAY: u32 = 4;
+        type Input = usize;
+
+        fn parse_input(&self, lines: &[String]) -> Result<usize, ParseError> {
+            Ok(lines.len())
+        }
+
+        fn solve_part_1(&self, input: &usize) -> Answer {
+            Answer::Int(*input as i64)
+        }
+
+        fn solve_part_2(&self, input: &usize) -> Answer {
+            Answer::Grid(vec!["#".repeat(*input); 2])
+        }
+    }
+
+    #[test]
+    fn saves_and_loads_answers() {
+        let dir = TempDir::new("verify-answers");
+        assert!(load_answers(&dir, 7).unwrap().is_empty());
+
+        let answers = BTreeMap::from([
+            (1, Answer::Int(-42)),
+            (2, Answer::Grid(vec!["#..".to_string(), ".#.".to_string()])),
+        ]);
+        save_answers(&dir, 7, &answers).unwrap();
+        assert_eq!(load_answers(&dir, 7).unwrap(), answers);
+
+        let text = BTreeMap::from([(2, Answer::Text("ABC".to_string()))]);
+        save_answers(&dir, 8, &text).unwrap();
+        assert_eq!(load_answers(&dir, 8).unwrap(), text);
+    }
+
+    #[test]
+    fn compares_against_accepted_answers() {
+        let dir = TempDir::new("verify");
+        let answers_dir = dir.join("answers");
+        let mut registry = Registry::new(2024, dir.to_path_buf());
+        registry.register(Counter);
+        fs::create_dir_all(registry.inputs_dir()).unwrap();
+        fs::write(registry.input_path(4), "a\nb\nc\n").unwrap();
+        let grid = Answer::Grid(vec!["###".to_string(); 2]);
+        let verify = || run_verify(&registry, 4..=4, &[1, 2], &answers_dir, 1, None);
+
+        save_answers(&answers_dir, 4, &BTreeMap::from([(1, Answer::Int(3))])).unwrap();
+        let results = verify();
+        assert!(matches!(results[0].verdict, Verdict::Correct));
+        assert!(matches!(&results[1].verdict, Verdict::Unverified(actual) if *actual == grid));
+
+        save_answers(&answers_dir, 4, &BTreeMap::from([(1, Answer::Int(4))])).unwrap();
+        let results = verify();
+        assert!(matches!(
+            &results[0].verdict,
+            Verdict::Mismatch {
+                expected: Answer::Int(4),
+                actual: Answer::Int(3)
+            }
+        ));
+
+        // Only the unverified answer is recorded, leaving the mismatched one as accepted
+        assert_eq!(record_unverified(&results, &answers_dir), Ok(1));
+        assert_eq!(
+            load_answers(&answers_dir, 4).unwrap(),
+            BTreeMap::from([(1, Answer::Int(4)), (2, grid)])
+        );
+    }
+}
@@ -0,0 +1,239 @@
+use crate::{
+    example::ExampleResult,
+    runner::{self, format_duration, RunResult},
+    solver::Registry,
+    utils::InputSource,
+};
+use std::{
+    fs,
+    path::PathBuf,
+    thread,
+    time::{Duration, SystemTime},
+};
+
+/// How often the watched files are checked for changes
+pub const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// The answers, timings and example results of a single run of a day's solver.
+pub struct WatchRun {
+    pub results: Vec<RunResult>,
+    pub examples: Vec<ExampleResult>,
+}
+
+impl WatchRun {
+    fn run(
+        registry: &Registry,
+        day: u32,
+        parts: &[u32],
+        source: &InputSource,
+        timeout: Option<Duration>,
+    ) -> Self {
+        let results = runner::run_batch(registry, day..=day, parts, source, parts.len(), timeout);
+        let examples = runner::run_examples_batch(registry, day..=day, parts, timeout)
+            .into_iter()
+            .flat_map(|(_, examples)| examples)
+            .collect();
+        WatchRun { results, examples }
+    }
+}
+
+/// Return the files whose changes cause the given day to be solved again: its input file,
+/// if read from a file, and the fixtures of its examples.
+fn watched_files(registry: &Registry, day: u32, source: &InputSource) -> Vec<PathBuf> {
+    let examples = registry
+        .get(day)
+        .map_or_else(Vec::new, |solver| solver.examples());
+    source
+        .path(day)
+        .into_iter()
+        .chain(
+            examples
+                .iter()
+                .map(|example| example.path(&registry.examples_dir())),
+        )
+        .collect()
+}
+
+/// Return the modification time of each file, or None for files that cannot be read.
+fn snapshot(files: &[PathBuf]) -> Vec<Option<SystemTime>> {
+    files
+        .iter()
+        .map(|file| fs::metadata(file).and_then(|m| m.modified()).ok())
+        .collect()
+}
+
+/// Solve the selected parts of the given day along with its examples, then do so again
+/// whenever its input file or example fixtures change, printing how the answers and timings
+/// differ from the previous run. Each part and example is abandoned if it runs past the given
+/// time limit, and the number of abandoned threads still running is reported after each run.
+/// Runs until the process is interrupted.
+pub fn watch(
+    registry: &Registry,
+    day: u32,
+    parts: &[u32],
+    source: &InputSource,
+    timeout: Option<Duration>,
+) -> Result<(), String> {
+    if registry.get(day).is_none() {
+        return Err(format!("No solver registered for day {day}"));
+    }
+    if source.path(day).is_none() {
+        return Err("Input read from stdin cannot be watched".to_string());
+    }
+
+    let files = watched_files(registry, day, source);
+    println!("Watching for changes to:");
+    for file in &files {
+        println!("  {}", file.display());
+    }
+
+    let mut previous: Option<WatchRun> = None;
+    let mut last_snapshot = snapshot(&files);
+    for run_number in 1.. {
+        let current = WatchRun::run(registry, day, parts, source, timeout);
+        println!("\nRun #{run_number} of day {day}");
+        for line in describe_changes(previous.as_ref(), &current) {
+            println!("{line}");
+        }
+        let abandoned = runner::abandoned_threads();
+        if abandoned > 0 {
+            println!("{abandoned} thread(s) abandoned after timing out are still running");
+        }
+        previous = Some(current);
+
+        loop {
+            thread::sleep(POLL_INTERVAL);
+            let current_snapshot = snapshot(&files);
+            if current_snapshot != last_snapshot {
+                last_snapshot = current_snapshot;
+                break;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Describe the answers, timings and example results of the current run, noting how each
+/// differs from the previous run if there was one.
+fn describe_changes(previous: Option<&WatchRun>, current: &WatchRun) -> Vec<String> {
+    let mut lines = Vec::new();
+    for result in &current.results {
+        let before = previous.and_then(|previous| {
+            previous
+                .results
+                .iter()
+                .find(|before| before.part == result.part)
+        });
+
+        let answer = match &result.outcome {
+            Ok(answer) => answer.one_line(),
+            Err(err) => format!("{}: {err}", err.status()),
+        };
+        let answer_change = match before.map(|before| &before.outcome) {
+            None => String::new(),
+            Some(outcome) if *outcome == result.outcome => " (unchanged)".to_string(),
+            Some(Ok(answer)) => format!(" (was {})", answer.one_line()),
+            Some(Err(_)) => " (previously failed)".to_string(),
+        };
+        let time_change = match before {
+            Some(before) if !before.elapsed.is_zero() => format!(
+                " ({:+.1}% from {})",
+                (result.elapsed.as_secs_f64() / before.elapsed.as_secs_f64() - 1.0) * 100.0,
+                format_duration(before.elapsed)
+            ),
+            _ => String::new(),
+        };
+        lines.push(format!(
+            "Part {}: {answer}{answer_change} in {}{time_change}",
+            result.part,
+            format_duration(result.elapsed)
+        ));
+    }
+
+    let num_passed = current.examples.iter().filter(|e| e.passed()).count();
+    lines.push(format!(
+        "Examples: {num_passed} passed, {} failed",
+        current.examples.len() - num_passed
+    ));
+    for example in &current.examples {
+        let before = previous.and_then(|previous| {
+            previous
+                .examples
+                .iter()
+                .find(|before| before.file == example.file && before.part == example.part)
+        });
+        let status = match (example.passed(), before.map(ExampleResult::passed)) {
+            (true, Some(false)) => "now passing".to_string(),
+            (true, _) => continue,
+            (false, _) => match &example.outcome {
+                Ok(actual) => format!(
+                    "expected {}, got {}",
+                    example.expected.one_line(),
+                    actual.one_line()
+                ),
+                Err(err) => err.clone(),
+            },
+        };
+        lines.push(format!(
+            "  {} part {}: {status}",
+            example.file, example.part
+        ));
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answer::Answer;
+
+    fn run(answer: i64, millis: u64, example: i64) -> WatchRun {
+        WatchRun {
+            results: vec![RunResult {
+                day: 1,
+                part: 1,
+                outcome: Ok(Answer::Int(answer)),
+                elapsed: Duration::from_millis(millis),
+                alloc: None,
+            }],
+            examples: vec![ExampleResult {
+                file: "day01.txt",
+                part: 1,
+                expected: Answer::Int(11),
+                outcome: Ok(Answer::Int(example)),
+            }],
+        }
+    }
+
+    #[test]
+    fn describes_changes_from_previous_run() {
+        let first = run(5, 10, 12);
+        assert_eq!(
+            describe_changes(None, &first),
+            vec![
+                "Part 1: 5 in 10.00 ms",
+                "Examples: 0 passed, 1 failed",
+                "  day01.txt part 1: expected 11, got 12",
+            ]
+        );
+
+        let second = run(7, 15, 11);
+        assert_eq!(
+            describe_changes(Some(&first), &second),
+            vec![
+                "Part 1: 7 (was 5) in 15.00 ms (+50.0% from 10.00 ms)",
+                "Examples: 1 passed, 0 failed",
+                "  day01.txt part 1: now passing",
+            ]
+        );
+
+        let third = run(7, 15, 11);
+        assert_eq!(
+            describe_changes(Some(&second), &third),
+            vec![
+                "Part 1: 7 (unchanged) in 15.00 ms (+0.0% from 15.00 ms)",
+                "Examples: 1 passed, 0 failed",
+            ]
+        );
+    }
+}
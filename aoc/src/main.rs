@@ -0,0 +1,503 @@
+use aoc_core::{
+    bench, client, example::ExampleResult, external, history, runner, scaffold, solver, submit,
+    verify, InputSource, Registry,
+};
+use clap::{Args, Parser, Subcommand};
+use std::{
+    ops::RangeInclusive,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::Duration,
+};
+
+/// Count allocations so that heap usage can be reported alongside timings
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static GLOBAL: aoc_core::alloc::CountingAllocator = aoc_core::alloc::CountingAllocator;
+
+/// The AoC problem(s) defined by the day(s) and part(s)
+#[derive(Parser)]
+struct AoCProblem {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// The event year to solve, defaulting to the latest year with solutions
+    #[arg(short = 'y', long = "year", global = true)]
+    year: Option<u32>,
+
+    #[command(flatten)]
+    selection: Selection,
+
+    /// Read the puzzle input from this file instead of inputs/dayNN.txt, or from stdin if "-"
+    #[arg(short = 'i', long = "input")]
+    input: Option<PathBuf>,
+
+    /// Solve a single day again whenever its input file or example fixtures change
+    #[arg(short = 'w', long = "watch")]
+    watch: bool,
+
+    /// Number of solvers to run in parallel, defaulting to the number of available cores
+    #[arg(short = 'j', long = "jobs", global = true, value_parser = clap::value_parser!(u32).range(1..))]
+    jobs: Option<u32>,
+
+    /// Seconds each solver, example or benchmark may run before it is abandoned, or 0 for no
+    /// limit [default: 60]
+    #[arg(short = 't', long = "timeout", global = true)]
+    timeout: Option<u64>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Measure parse and solve times of the selected solvers over repeated runs
+    Bench {
+        #[command(flatten)]
+        selection: Selection,
+
+        /// Number of timed iterations, which follow a single warm-up run
+        #[arg(short = 'n', long = "iterations", default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        iterations: u32,
+
+        /// Read the puzzle input from this file instead of inputs/dayNN.txt, or from stdin if
+        /// "-". Runs on other inputs are not recorded in the bench history
+        #[arg(short = 'i', long = "input")]
+        input: Option<PathBuf>,
+
+        /// Compare against the previous recorded run, or the latest run with the given name,
+        /// failing if any stage became significantly slower
+        #[arg(long = "compare", value_name = "NAME", num_args = 0..=1, conflicts_with = "input")]
+        compare: Option<Option<String>>,
+
+        /// Record this run under a name, so that later runs can be compared against it
+        #[arg(long = "name", conflicts_with = "input")]
+        name: Option<String>,
+    },
+
+    /// Solve the worked examples declared by the selected solvers
+    Examples {
+        #[command(flatten)]
+        selection: Selection,
+    },
+
+    /// Download the puzzle inputs for the given days, skipping any already on disk
+    Fetch {
+        /// A single day, or a range of days such as 3..=8 or 3..9
+        #[arg(short = 'd', long = "day", value_parser = parse_days)]
+        days: RangeInclusive<u32>,
+
+        /// Base URL of the puzzle site
+        #[arg(long = "base-url", env = client::BASE_URL_ENV_VAR, default_value = client::DEFAULT_BASE_URL)]
+        base_url: String,
+    },
+
+    /// Generate the module for a new day and create its empty input and example files
+    New {
+        /// The day to create
+        #[arg(short = 'd', long = "day", value_parser = clap::value_parser!(u32).range(1..=25))]
+        day: u32,
+    },
+
+    /// Solve a single part on its default input and submit the answer to the puzzle site
+    Submit {
+        /// The day to submit an answer for
+        #[arg(short = 'd', long = "day", value_parser = clap::value_parser!(u32).range(1..=25))]
+        day: u32,
+
+        /// The part to submit an answer for
+        #[arg(short = 'p', long = "part", value_parser = clap::value_parser!(u32).range(1..3))]
+        part: u32,
+
+        /// Base URL of the puzzle site
+        #[arg(long = "base-url", env = client::BASE_URL_ENV_VAR, default_value = client::DEFAULT_BASE_URL)]
+        base_url: String,
+
+        /// Fail instead of waiting when the site requires a pause between submissions
+        #[arg(long = "no-wait")]
+        no_wait: bool,
+    },
+
+    /// Rerun the selected solvers and compare their answers against the accepted answers
+    Verify {
+        #[command(flatten)]
+        selection: Selection,
+
+        /// Directory containing the accepted answers, stored as dayNN.toml. Defaults to
+        /// the answers directory of the year's crate
+        #[arg(long = "answers")]
+        answers_dir: Option<PathBuf>,
+
+        /// Accept the current answers of any parts that have no accepted answer yet
+        #[arg(long = "record")]
+        record: bool,
+    },
+}
+
+/// The days and parts to be solved
+#[derive(Args)]
+struct Selection {
+    /// A single day, or a range of days such as 3..=8 or 3..9. All days if omitted
+    #[arg(short = 'd', long = "day", value_parser = parse_days)]
+    days: Option<RangeInclusive<u32>>,
+
+    /// Solve only this part, or both parts if omitted
+    #[arg(short = 'p', long = "part", value_parser = clap::value_parser!(u32).range(1..3))]
+    part: Option<u32>,
+
+    /// Select every registered solver
+    #[arg(short = 'a', long = "all", conflicts_with = "days")]
+    all: bool,
+}
+
+impl Selection {
+    fn days(&self) -> RangeInclusive<u32> {
+        self.days.clone().unwrap_or(1..=25)
+    }
+
+    fn parts(&self) -> Vec<u32> {
+        self.part.map_or(vec![1, 2], |part| vec![part])
+    }
+
+    /// Return whether any days or parts were selected explicitly.
+    fn is_given(&self) -> bool {
+        self.days.is_some() || self.part.is_some() || self.all
+    }
+
+    fn is_single_day(&self) -> bool {
+        !self.all && self.days().start() == self.days().end()
+    }
+}
+
+/// Parse a single day "N" or a range of days "A..B" / "A..=B", each within 1 to 25.
+fn parse_days(arg: &str) -> Result<RangeInclusive<u32>, String> {
+    let parse_day = |s: &str, valid: RangeInclusive<u32>| -> Result<u32, String> {
+        let day: u32 = s
+            .trim()
+            .parse()
+            .map_err(|_| format!("'{s}' is not a valid day"))?;
+        if valid.contains(&day) {
+            Ok(day)
+        } else {
+            Err(format!("day {day} is not in {valid:?}"))
+        }
+    };
+
+    let days = if let Some((start, end)) = arg.split_once("..=") {
+        parse_day(start, 1..=25)?..=parse_day(end, 1..=25)?
+    } else if let Some((start, end)) = arg.split_once("..") {
+        // The end of an exclusive range is one past the last day
+        parse_day(start, 1..=25)?..=parse_day(end, 2..=26)? - 1
+    } else {
+        let day = parse_day(arg, 1..=25)?;
+        day..=day
+    };
+
+    if days.is_empty() {
+        Err(format!("'{arg}' does not contain any days"))
+    } else {
+        Ok(days)
+    }
+}
+
+fn main() -> ExitCode {
+    let args = AoCProblem::parse();
+    // The selection and input of a subcommand are its own, so would be ignored before it
+    if args.command.is_some() && (args.selection.is_given() || args.input.is_some() || args.watch) {
+        eprintln!("Days, parts, input and watch mode must be given after the subcommand");
+        return ExitCode::FAILURE;
+    }
+    let registry = match solver::select_year(registries(), args.year) {
+        Ok(registry) => registry,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match args.command {
+        None if args.watch => watch(
+            &registry,
+            &args.selection,
+            args.input,
+            timeout(args.timeout),
+        ),
+        None => run(
+            &registry,
+            &args.selection,
+            args.input,
+            jobs(args.jobs),
+            timeout(args.timeout),
+        ),
+        Some(Command::Bench {
+            selection,
+            iterations,
+            input,
+            compare,
+            name,
+        }) => {
+            let record = input.is_none();
+            let Some(source) = input_source(&registry, &selection, input) else {
+                return ExitCode::FAILURE;
+            };
+            let results = bench::run_bench(
+                &registry,
+                selection.days(),
+                &selection.parts(),
+                &source,
+                iterations as usize,
+                timeout(args.timeout),
+            );
+            if results.is_empty() {
+                eprintln!("No solvers registered for the requested days");
+                return ExitCode::FAILURE;
+            }
+
+            bench::print_bench(&results, iterations as usize);
+            let success = results.iter().all(|result| result.outcome.is_ok());
+            if !record {
+                return exit_code(success);
+            }
+            match history::record_bench(&registry, &results, iterations as usize, name, compare) {
+                Ok(no_slowdowns) => exit_code(success && no_slowdowns),
+                Err(err) => {
+                    eprintln!("{err}");
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        Some(Command::Examples { selection }) => {
+            let results = runner::run_examples_batch(
+                &registry,
+                selection.days(),
+                &selection.parts(),
+                timeout(args.timeout),
+            );
+            if results.is_empty() {
+                eprintln!("No solvers registered for the requested days");
+                return ExitCode::FAILURE;
+            }
+
+            runner::print_examples(&results);
+            exit_code(
+                results
+                    .iter()
+                    .flat_map(|(_, r)| r)
+                    .all(ExampleResult::passed),
+            )
+        }
+        Some(Command::Fetch { days, base_url }) => {
+            match client::fetch_inputs(&registry, days, &base_url) {
+                Ok(success) => exit_code(success),
+                Err(err) => {
+                    eprintln!("{err}");
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        Some(Command::New { day }) => match scaffold::scaffold_day(&registry, day) {
+            Ok(paths) => {
+                for path in paths {
+                    println!("Wrote {}", path.display());
+                }
+                ExitCode::SUCCESS
+            }
+            Err(err) => {
+                eprintln!("{err}");
+                ExitCode::FAILURE
+            }
+        },
+        Some(Command::Submit {
+            day,
+            part,
+            base_url,
+            no_wait,
+        }) => match submit::solve_and_submit(&registry, day, part, &base_url, !no_wait) {
+            Ok(outcome) => {
+                println!("{outcome}");
+                exit_code(outcome == submit::SubmitOutcome::Correct)
+            }
+            Err(err) => {
+                eprintln!("{err}");
+                ExitCode::FAILURE
+            }
+        },
+        Some(Command::Verify {
+            selection,
+            answers_dir,
+            record,
+        }) => {
+            let answers_dir = answers_dir.unwrap_or_else(|| registry.answers_dir());
+            let results = verify::run_verify(
+                &registry,
+                selection.days(),
+                &selection.parts(),
+                &answers_dir,
+                jobs(args.jobs),
+                timeout(args.timeout),
+            );
+            if results.is_empty() {
+                eprintln!("No solvers registered for the requested days");
+                return ExitCode::FAILURE;
+            }
+
+            verify::print_verify(&results);
+            if record {
+                match verify::record_unverified(&results, &answers_dir) {
+                    Ok(num_recorded) => println!("Recorded {num_recorded} new accepted answer(s)"),
+                    Err(err) => {
+                        eprintln!("{err}");
+                        return ExitCode::FAILURE;
+                    }
+                }
+            }
+            exit_code(results.iter().all(|result| {
+                !matches!(
+                    result.verdict,
+                    verify::Verdict::Mismatch { .. } | verify::Verdict::Failed(_)
+                )
+            }))
+        }
+    }
+}
+
+/// Solve the selected days and parts, printing a single answer in full or a summary
+/// table when more than one is selected.
+fn run(
+    registry: &Registry,
+    selection: &Selection,
+    input: Option<PathBuf>,
+    jobs: usize,
+    timeout: Option<Duration>,
+) -> ExitCode {
+    let Some(source) = input_source(registry, selection, input) else {
+        return ExitCode::FAILURE;
+    };
+
+    if let (true, Some(part)) = (selection.is_single_day(), selection.part) {
+        let day = *selection.days().start();
+        return report(runner::solve_single(registry, day, part, &source));
+    }
+
+    let results = runner::run_batch(
+        registry,
+        selection.days(),
+        &selection.parts(),
+        &source,
+        jobs,
+        timeout,
+    );
+    if results.is_empty() {
+        eprintln!("No solvers registered for the requested days");
+        return ExitCode::FAILURE;
+    }
+
+    runner::print_summary(&results);
+    exit_code(results.iter().all(|result| result.outcome.is_ok()))
+}
+
+/// Solve a single day's selected parts and examples, then do so again whenever its input
+/// or example fixtures change.
+fn watch(
+    registry: &Registry,
+    selection: &Selection,
+    input: Option<PathBuf>,
+    timeout: Option<Duration>,
+) -> ExitCode {
+    if !selection.is_single_day() {
+        eprintln!("Watch mode can only be used with a single day");
+        return ExitCode::FAILURE;
+    }
+    let Some(source) = input_source(registry, selection, input) else {
+        return ExitCode::FAILURE;
+    };
+
+    let day = *selection.days().start();
+    report(aoc_core::watch::watch(
+        registry,
+        day,
+        &selection.parts(),
+        &source,
+        timeout,
+    ))
+}
+
+/// Return the registries of every year with solutions, in increasing order of year.
+fn registries() -> Vec<Registry> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("CLI crate should be inside the workspace");
+    vec![
+        external::python_registry(2023, &root.join("AoC2023")),
+        aoc2024::registry(),
+    ]
+}
+
+/// Determine the input source from the command-line argument, which may only override
+/// the default input when a single day is selected.
+fn input_source(
+    registry: &Registry,
+    selection: &Selection,
+    input: Option<PathBuf>,
+) -> Option<InputSource> {
+    let source = InputSource::from_arg(input, &registry.inputs_dir());
+    if !selection.is_single_day() && source != registry.default_input() {
+        eprintln!("An input override can only be used with a single day");
+        return None;
+    }
+    Some(source)
+}
+
+/// Return the requested number of parallel jobs, or the default if none was requested.
+fn jobs(arg: Option<u32>) -> usize {
+    arg.map_or_else(runner::default_jobs, |jobs| jobs as usize)
+}
+
+/// Return the requested time limit per solver, the default if none was requested, or no
+/// limit if zero seconds were requested.
+fn timeout(arg: Option<u64>) -> Option<Duration> {
+    match arg {
+        None => Some(runner::DEFAULT_TIMEOUT),
+        Some(0) => None,
+        Some(secs) => Some(Duration::from_secs(secs)),
+    }
+}
+
+/// Print the error of a failed command.
+fn report(result: Result<(), String>) -> ExitCode {
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn exit_code(success: bool) -> ExitCode {
+    if success {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_day_ranges() {
+        assert_eq!(parse_days("7"), Ok(7..=7));
+        assert_eq!(parse_days("3..9"), Ok(3..=8));
+        assert_eq!(parse_days("3..=9"), Ok(3..=9));
+        assert_eq!(parse_days("1..26"), Ok(1..=25));
+        assert_eq!(parse_days(" 1 ..= 25 "), Ok(1..=25));
+
+        assert!(parse_days("5..5").is_err());
+        assert!(parse_days("9..=3").is_err());
+        assert!(parse_days("9..3").is_err());
+        assert!(parse_days("0..=3").is_err());
+        assert!(parse_days("3..=26").is_err());
+        assert!(parse_days("3..27").is_err());
+        assert!(parse_days("1..1").is_err());
+        assert!(parse_days("x").is_err());
+    }
+}